@@ -0,0 +1,85 @@
+//! Audio for VATSIM addresses frequencies in whole Hz of the channel name, so 118.005 is 118005000 even
+//! though its 8.33 kHz carrier is 118.000 MHz. [`Transceiver`] serializes in the AFV API's JSON shape.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{RadioFrequency, RadioFrequencyError};
+
+/// The channel name in Hz, never the carrier.
+pub fn to_hz(frequency: RadioFrequency) -> u32 {
+    frequency.hz()
+}
+
+pub fn from_hz(hz: u32) -> Result<RadioFrequency, RadioFrequencyError> {
+    RadioFrequency::from_hz(hz)
+}
+
+/// A transmitter/receiver site for one frequency.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Transceiver {
+    #[serde(rename = "ID")]
+    pub id: u16,
+    #[serde(with = "hz")]
+    pub frequency: RadioFrequency,
+    pub lat_deg: f64,
+    pub lon_deg: f64,
+    pub height_msl_m: f64,
+    pub height_agl_m: f64,
+}
+
+/// Groups transceivers by the frequency they serve.
+pub fn by_frequency(transceivers: &[Transceiver]) -> BTreeMap<RadioFrequency, Vec<Transceiver>> {
+    let mut groups: BTreeMap<RadioFrequency, Vec<Transceiver>> = BTreeMap::new();
+    for transceiver in transceivers {
+        groups.entry(transceiver.frequency).or_default().push(*transceiver);
+    }
+    groups
+}
+
+/// Serializes a [`RadioFrequency`] as AFV Hz.
+mod hz {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    use crate::RadioFrequency;
+
+    pub fn serialize<S: Serializer>(frequency: &RadioFrequency, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(super::to_hz(*frequency))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<RadioFrequency, D::Error> {
+        super::from_hz(u32::deserialize(deserializer)?).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::freq;
+
+    #[test]
+    fn hz() {
+        assert_eq!(to_hz(freq!("118.005")), 118_005_000);
+        assert_ne!(to_hz(freq!("118.005")), freq!("118.005").carrier_hz());
+        assert_eq!(from_hz(118_005_000), Ok(freq!("118.005")));
+        assert_eq!(from_hz(118_008_333), Err(RadioFrequencyError::NotWholeKhz));
+    }
+
+    #[test]
+    fn grouping() {
+        let site = |id, frequency| Transceiver {
+            id,
+            frequency,
+            lat_deg: 51.47,
+            lon_deg: -0.46,
+            height_msl_m: 25.0,
+            height_agl_m: 20.0,
+        };
+        let transceivers = [site(0, freq!("118.505")), site(1, freq!("121.900")), site(2, freq!("118.505"))];
+        let groups = by_frequency(&transceivers);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[&freq!("118.505")].iter().map(|t| t.id).collect::<Vec<_>>(), [0, 2]);
+    }
+}
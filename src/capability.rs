@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{ChannelFilter, RadioFrequency};
+
+/// What an aircraft's COM radio can tune.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RadioCapability {
+    /// Legacy 25 kHz radio ending at 135.975.
+    Channels720,
+    /// 25 kHz radio covering the whole band.
+    Channels760,
+    /// 8.33 kHz capable radio, which tunes the 2280 8.33 kHz channels as well as the 25 kHz ones.
+    Channels8_33,
+}
+
+impl RadioCapability {
+    pub fn can_tune(&self, frequency: &RadioFrequency) -> bool {
+        match self {
+            Self::Channels720 => frequency.is_25_khz_spaced() && frequency.left() <= 135,
+            Self::Channels760 => frequency.is_25_khz_spaced(),
+            Self::Channels8_33 => true,
+        }
+    }
+
+    /// The channels this radio steps through.
+    pub fn channel_filter(&self) -> ChannelFilter {
+        match self {
+            Self::Channels720 | Self::Channels760 => ChannelFilter::Khz25,
+            Self::Channels8_33 => ChannelFilter::All,
+        }
+    }
+
+    /// Reads the equipment codes of ICAO flight plan item 10a, e.g. "SDFGRWY" or "SDFGRWY/LB1".
+    /// "Y" means 8.33 kHz capable, "S" or "V" a 25 kHz radio. Returns `None` if no VHF radio is filed.
+    pub fn from_field10(equipment: &str) -> Option<RadioCapability> {
+        let equipment = equipment.split('/').next().unwrap_or_default();
+        if equipment.contains('Y') {
+            Some(Self::Channels8_33)
+        } else if equipment.contains(['S', 'V']) {
+            Some(Self::Channels760)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{freq, ChannelFilter, RadioCapability, RadioFrequency};
+
+    #[test]
+    fn can_tune() {
+        let counts = [(RadioCapability::Channels720, 720), (RadioCapability::Channels760, 760), (RadioCapability::Channels8_33, 3040)];
+        for (capability, count) in counts {
+            assert_eq!(RadioFrequency::channels(ChannelFilter::All).filter(|f| capability.can_tune(f)).count(), count);
+        }
+        assert!(!RadioCapability::Channels720.can_tune(&freq!("136.025")));
+        assert!(RadioCapability::Channels760.can_tune(&freq!("136.025")));
+        assert!(!RadioCapability::Channels760.can_tune(&freq!("132.605")));
+        assert!(RadioCapability::Channels8_33.can_tune(&freq!("132.605")));
+    }
+
+    #[test]
+    fn field10() {
+        assert_eq!(RadioCapability::from_field10("SDFGRWY/LB1"), Some(RadioCapability::Channels8_33));
+        assert_eq!(RadioCapability::from_field10("SDFG/C"), Some(RadioCapability::Channels760));
+        assert_eq!(RadioCapability::from_field10("V/C"), Some(RadioCapability::Channels760));
+        assert_eq!(RadioCapability::from_field10("N/N"), None);
+        assert_eq!(RadioCapability::from_field10("DG/SY"), None);
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{ChannelFilter, RadioFrequency, CHANNEL_COUNT};
+
+/// Iterator over the channel names in a part of the band, in ascending order.
+#[derive(Debug, Clone)]
+pub struct Channels {
+    next: u16,
+    end: u16,
+    filter: ChannelFilter,
+}
+
+impl Iterator for Channels {
+    type Item = RadioFrequency;
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.next < self.end {
+            let frequency = RadioFrequency::from_ordinal(self.next);
+            self.next += 1;
+            if self.filter.matches(&frequency) {
+                return Some(frequency);
+            }
+        }
+        None
+    }
+}
+
+impl RadioFrequency {
+    /// Every channel in the band matching `filter`.
+    pub fn channels(filter: ChannelFilter) -> Channels {
+        Channels {
+            next: 0,
+            end: CHANNEL_COUNT,
+            filter,
+        }
+    }
+
+    /// Every channel from `start` to `end` inclusive matching `filter`. Empty if `start` is above `end`.
+    pub fn channels_between(start: RadioFrequency, end: RadioFrequency, filter: ChannelFilter) -> Channels {
+        Channels {
+            next: start.ordinal(),
+            end: end.ordinal() + 1,
+            filter,
+        }
+    }
+}
+
+/// Every channel matching `filter` whose name truncated to 10 kHz is `ten_khz`, as simulators that drop the
+/// third decimal report it. 12150 gives 121.500 and 121.505.
+pub(crate) fn truncated_to_10_khz(ten_khz: u32, filter: ChannelFilter) -> Vec<RadioFrequency> {
+    let khz = ten_khz.saturating_mul(10);
+    (khz..khz.saturating_add(10))
+        .filter_map(|khz| RadioFrequency::from_khz(khz).ok())
+        .filter(|frequency| filter.matches(frequency))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ChannelFilter, RadioFrequency};
+
+    #[test]
+    fn whole_band() {
+        assert_eq!(RadioFrequency::channels(ChannelFilter::Khz25).count(), 760);
+        assert_eq!(RadioFrequency::channels(ChannelFilter::Khz8_33).count(), 2280);
+        assert_eq!(RadioFrequency::channels(ChannelFilter::All).count(), 3040);
+        assert!(RadioFrequency::channels(ChannelFilter::All).zip(RadioFrequency::channels(ChannelFilter::All).skip(1)).all(|(a, b)| a < b));
+        assert_eq!(RadioFrequency::channels(ChannelFilter::Khz8_33).next().unwrap(), "118.005".parse().unwrap());
+    }
+
+    #[test]
+    fn between() {
+        let start = "121.500".parse().unwrap();
+        let end = "121.600".parse().unwrap();
+        let channels: Vec<_> = RadioFrequency::channels_between(start, end, ChannelFilter::Khz25).map(|f| f.to_string()).collect();
+        assert_eq!(channels, ["121.500", "121.525", "121.550", "121.575", "121.600"]);
+        assert_eq!(RadioFrequency::channels_between(start, end, ChannelFilter::All).count(), 17);
+        assert_eq!(RadioFrequency::channels_between(end, start, ChannelFilter::All).count(), 0);
+    }
+}
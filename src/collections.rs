@@ -0,0 +1,183 @@
+use crate::{ChannelFilter, Channels, RadioFrequency, CHANNEL_COUNT};
+
+/// An inclusive, non-empty range of channels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FrequencyRange {
+    start: RadioFrequency,
+    end: RadioFrequency,
+}
+
+impl FrequencyRange {
+    /// Returns `None` if `start` is above `end`.
+    pub const fn new(start: RadioFrequency, end: RadioFrequency) -> Option<FrequencyRange> {
+        if start.index().get() > end.index().get() {
+            return None;
+        }
+        Some(FrequencyRange { start, end })
+    }
+
+    pub const fn start(&self) -> RadioFrequency {
+        self.start
+    }
+
+    pub const fn end(&self) -> RadioFrequency {
+        self.end
+    }
+
+    pub fn contains(&self, frequency: &RadioFrequency) -> bool {
+        (self.start..=self.end).contains(frequency)
+    }
+
+    pub fn intersection(&self, other: &FrequencyRange) -> Option<FrequencyRange> {
+        FrequencyRange::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    pub fn iter(&self, filter: ChannelFilter) -> Channels {
+        RadioFrequency::channels_between(self.start, self.end, filter)
+    }
+}
+
+impl IntoIterator for FrequencyRange {
+    type Item = RadioFrequency;
+    type IntoIter = Channels;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter(ChannelFilter::All)
+    }
+}
+
+const WORDS: usize = (CHANNEL_COUNT as usize).div_ceil(64);
+
+/// A set of channels stored as one bit per channel in the band.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FrequencySet {
+    bits: [u64; WORDS],
+}
+
+impl Default for FrequencySet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FrequencySet {
+    pub fn new() -> FrequencySet {
+        FrequencySet { bits: [0; WORDS] }
+    }
+
+    fn position(frequency: &RadioFrequency) -> (usize, u64) {
+        let ordinal = frequency.ordinal() as usize;
+        (ordinal / 64, 1 << (ordinal % 64))
+    }
+
+    /// Returns whether the frequency was newly inserted.
+    pub fn insert(&mut self, frequency: RadioFrequency) -> bool {
+        let (word, mask) = Self::position(&frequency);
+        let inserted = self.bits[word] & mask == 0;
+        self.bits[word] |= mask;
+        inserted
+    }
+
+    /// Returns whether the frequency was present.
+    pub fn remove(&mut self, frequency: &RadioFrequency) -> bool {
+        let (word, mask) = Self::position(frequency);
+        let present = self.bits[word] & mask != 0;
+        self.bits[word] &= !mask;
+        present
+    }
+
+    pub fn contains(&self, frequency: &RadioFrequency) -> bool {
+        let (word, mask) = Self::position(frequency);
+        self.bits[word] & mask != 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.bits.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|&word| word == 0)
+    }
+
+    pub fn union(&self, other: &FrequencySet) -> FrequencySet {
+        self.combine(other, |a, b| a | b)
+    }
+
+    pub fn intersection(&self, other: &FrequencySet) -> FrequencySet {
+        self.combine(other, |a, b| a & b)
+    }
+
+    /// The frequencies in `self` that are not in `other`.
+    pub fn difference(&self, other: &FrequencySet) -> FrequencySet {
+        self.combine(other, |a, b| a & !b)
+    }
+
+    fn combine(&self, other: &FrequencySet, op: impl Fn(u64, u64) -> u64) -> FrequencySet {
+        let mut result = FrequencySet::new();
+        for (word, (a, b)) in result.bits.iter_mut().zip(self.bits.iter().zip(other.bits.iter())) {
+            *word = op(*a, *b);
+        }
+        result
+    }
+
+    /// The frequencies in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = RadioFrequency> + '_ {
+        RadioFrequency::channels(ChannelFilter::All).filter(|frequency| self.contains(frequency))
+    }
+}
+
+impl Extend<RadioFrequency> for FrequencySet {
+    fn extend<T: IntoIterator<Item = RadioFrequency>>(&mut self, iter: T) {
+        for frequency in iter {
+            self.insert(frequency);
+        }
+    }
+}
+
+impl FromIterator<RadioFrequency> for FrequencySet {
+    fn from_iter<T: IntoIterator<Item = RadioFrequency>>(iter: T) -> Self {
+        let mut set = FrequencySet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl From<FrequencyRange> for FrequencySet {
+    fn from(value: FrequencyRange) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{freq, ChannelFilter, FrequencyRange, FrequencySet};
+
+    #[test]
+    fn range() {
+        let a = FrequencyRange::new(freq!("121.500"), freq!("121.600")).unwrap();
+        let b = FrequencyRange::new(freq!("121.575"), freq!("122.000")).unwrap();
+        assert!(FrequencyRange::new(freq!("121.600"), freq!("121.500")).is_none());
+        assert!(a.contains(&freq!("121.600")));
+        assert!(!a.contains(&freq!("121.605")));
+        assert_eq!(a.intersection(&b), FrequencyRange::new(freq!("121.575"), freq!("121.600")));
+        assert_eq!(a.intersection(&FrequencyRange::new(freq!("122.500"), freq!("122.600")).unwrap()), None);
+        assert_eq!(a.iter(ChannelFilter::Khz25).count(), 5);
+        assert_eq!(a.into_iter().count(), 17);
+    }
+
+    #[test]
+    fn set() {
+        let mut assigned: FrequencySet = [freq!("121.500"), freq!("118.005"), freq!("136.990")].into_iter().collect();
+        assert_eq!(assigned.len(), 3);
+        assert!(!assigned.insert(freq!("121.500")));
+        assert!(assigned.contains(&freq!("136.990")));
+
+        let blocked = FrequencySet::from(FrequencyRange::new(freq!("121.400"), freq!("121.600")).unwrap());
+        assert_eq!(assigned.intersection(&blocked).iter().collect::<Vec<_>>(), [freq!("121.500")]);
+        assert_eq!(assigned.difference(&blocked).iter().collect::<Vec<_>>(), [freq!("118.005"), freq!("136.990")]);
+        assert_eq!(assigned.union(&blocked).len(), blocked.len() + 2);
+
+        assert!(assigned.remove(&freq!("121.500")));
+        assert!(!assigned.remove(&freq!("121.500")));
+        assert!(FrequencySet::new().is_empty());
+    }
+}
@@ -0,0 +1,133 @@
+/// Why [`parse_decimal`] rejected its input. Each frequency type maps these onto its own error enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DecimalError {
+    NotEnoughParts,
+    TooManyParts,
+    MissingWhole,
+    MissingDecimals,
+    TooManyDecimals,
+    UnexpectedSign,
+    UnexpectedWhitespace,
+    InvalidCharacter,
+}
+
+/// Length in bytes of the whitespace character starting at `bytes[i]`, or 0 if there is none. Covers every
+/// character `char::is_whitespace` accepts, so a non-breaking space pasted from a chart counts as whitespace.
+const fn whitespace_len(bytes: &[u8], i: usize) -> usize {
+    let rest = bytes.len() - i;
+    match bytes[i] {
+        b'\t' | b'\n' | 0x0b | 0x0c | b'\r' | b' ' => 1,
+        // U+0085 and U+00A0.
+        0xc2 if rest >= 2 && matches!(bytes[i + 1], 0x85 | 0xa0) => 2,
+        // U+1680.
+        0xe1 if rest >= 3 && bytes[i + 1] == 0x9a && bytes[i + 2] == 0x80 => 3,
+        // U+2000 to U+200A, U+2028, U+2029, U+202F and U+205F.
+        0xe2 if rest >= 3 && bytes[i + 1] == 0x80 && matches!(bytes[i + 2], 0x80..=0x8a | 0xa8 | 0xa9 | 0xaf) => 3,
+        0xe2 if rest >= 3 && bytes[i + 1] == 0x81 && bytes[i + 2] == 0x9f => 3,
+        // U+3000.
+        0xe3 if rest >= 3 && bytes[i + 1] == 0x80 && bytes[i + 2] == 0x80 => 3,
+        _ => 0,
+    }
+}
+
+/// Parses an unsigned decimal such as "118.5" into its whole part and its fraction scaled to `decimals`
+/// places, so with 3 places "118.5", "118.50" and "118.500" all give (118, 500). The whole part saturates
+/// at `u32::MAX` rather than overflowing; callers range-check it anyway.
+pub(crate) const fn parse_decimal(s: &str, decimals: usize, dot_required: bool) -> Result<(u32, u16), DecimalError> {
+    let bytes = s.as_bytes();
+    let (mut sign, mut whitespace, mut invalid) = (false, false, false);
+    let mut dots = 0;
+    let mut dot = bytes.len();
+    let mut i = 0;
+    while i < bytes.len() {
+        let space = whitespace_len(bytes, i);
+        if space > 0 {
+            whitespace = true;
+            i += space;
+            continue;
+        }
+        match bytes[i] {
+            b'+' | b'-' => sign = true,
+            b'.' => {
+                if dots == 0 {
+                    dot = i;
+                }
+                dots += 1;
+            }
+            b'0'..=b'9' => {}
+            _ => invalid = true,
+        }
+        i += 1;
+    }
+    if sign {
+        return Err(DecimalError::UnexpectedSign);
+    }
+    if whitespace {
+        return Err(DecimalError::UnexpectedWhitespace);
+    }
+    if invalid {
+        return Err(DecimalError::InvalidCharacter);
+    }
+    if dots == 0 && dot_required {
+        return Err(DecimalError::NotEnoughParts);
+    }
+    if dots > 1 {
+        return Err(DecimalError::TooManyParts);
+    }
+
+    if dot == 0 {
+        return Err(DecimalError::MissingWhole);
+    }
+    let mut whole: u32 = 0;
+    let mut i = 0;
+    while i < dot {
+        whole = whole.saturating_mul(10).saturating_add((bytes[i] - b'0') as u32);
+        i += 1;
+    }
+
+    let given = if dots == 0 { 0 } else { bytes.len() - dot - 1 };
+    if dots == 1 && given == 0 {
+        return Err(DecimalError::MissingDecimals);
+    }
+    if given > decimals {
+        return Err(DecimalError::TooManyDecimals);
+    }
+    let mut fraction: u16 = 0;
+    let mut i = 0;
+    while i < decimals {
+        fraction *= 10;
+        if i < given {
+            fraction += (bytes[dot + 1 + i] - b'0') as u16;
+        }
+        i += 1;
+    }
+    Ok((whole, fraction))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_decimal, DecimalError};
+
+    #[test]
+    fn parse() {
+        assert_eq!(parse_decimal("118.5", 3, true), Ok((118, 500)));
+        assert_eq!(parse_decimal("118.05", 3, true), Ok((118, 50)));
+        assert_eq!(parse_decimal("375", 1, false), Ok((375, 0)));
+        assert_eq!(parse_decimal("415.5", 1, false), Ok((415, 5)));
+        assert_eq!(parse_decimal("375", 1, true), Err(DecimalError::NotEnoughParts));
+        assert_eq!(parse_decimal("415.55", 1, false), Err(DecimalError::TooManyDecimals));
+        assert_eq!(parse_decimal("415.", 1, false), Err(DecimalError::MissingDecimals));
+        assert_eq!(parse_decimal(".5", 1, false), Err(DecimalError::MissingWhole));
+        assert_eq!(parse_decimal("", 1, false), Err(DecimalError::MissingWhole));
+        assert_eq!(parse_decimal("99999999999.5", 1, false), Ok((u32::MAX, 5)));
+    }
+
+    #[test]
+    fn whitespace() {
+        for c in (0..=char::MAX as u32).filter_map(char::from_u32).filter(|c| c.is_whitespace()) {
+            assert_eq!(parse_decimal(&format!("118.5{c}"), 3, true), Err(DecimalError::UnexpectedWhitespace), "{c:?}");
+        }
+        assert_eq!(parse_decimal("118.5\u{200b}", 3, true), Err(DecimalError::InvalidCharacter));
+        assert_eq!(parse_decimal("1\u{2080}.5", 3, true), Err(DecimalError::InvalidCharacter));
+    }
+}
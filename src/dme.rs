@@ -0,0 +1,225 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::NavFrequency;
+
+/// The X or Y mode of a DME/TACAN channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum DmeMode {
+    X,
+    Y,
+}
+
+/// A DME/TACAN channel such as 18X. Channels 17 to 59 and 70 to 126 are paired with a VHF NAV
+/// frequency per ICAO Annex 10; channels 1 to 16 and 60 to 69 are DME/TACAN only.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "RawDmeChannel")]
+pub struct DmeChannel {
+    number: u8,
+    mode: DmeMode,
+}
+
+#[derive(Deserialize)]
+struct RawDmeChannel {
+    number: u8,
+    mode: DmeMode,
+}
+
+impl TryFrom<RawDmeChannel> for DmeChannel {
+    type Error = DmeError;
+    fn try_from(value: RawDmeChannel) -> Result<Self, Self::Error> {
+        DmeChannel::new(value.number, value.mode)
+    }
+}
+
+impl DmeChannel {
+    /// `number` must be between 1 and 126.
+    pub const fn new(number: u8, mode: DmeMode) -> Result<DmeChannel, DmeError> {
+        if number < 1 || number > 126 {
+            return Err(DmeError::InvalidNumber);
+        }
+        Ok(DmeChannel { number, mode })
+    }
+
+    pub const fn number(&self) -> u8 {
+        self.number
+    }
+
+    pub const fn mode(&self) -> DmeMode {
+        self.mode
+    }
+
+    /// The channel paired with a VHF NAV frequency. Every NAV frequency has one.
+    pub const fn from_nav_frequency(frequency: NavFrequency) -> DmeChannel {
+        let khz = frequency.khz();
+        let mode = if khz % 100 == 50 { DmeMode::Y } else { DmeMode::X };
+        let number = if khz < 112_300 {
+            17 + (khz - 108_000) / 100
+        } else {
+            70 + (khz - 112_300) / 100
+        };
+        DmeChannel { number: number as u8, mode }
+    }
+
+    /// The VHF NAV frequency this channel is paired with, if any.
+    pub const fn nav_frequency(&self) -> Option<NavFrequency> {
+        let base = match self.number {
+            17..=59 => 108_000 + (self.number as u32 - 17) * 100,
+            70..=126 => 112_300 + (self.number as u32 - 70) * 100,
+            _ => return None,
+        };
+        let khz = match self.mode {
+            DmeMode::X => base,
+            DmeMode::Y => base + 50,
+        };
+        match NavFrequency::from_khz(khz) {
+            Ok(frequency) => Some(frequency),
+            Err(_) => None,
+        }
+    }
+
+    /// The frequency the aircraft interrogates on, from 1025 to 1150 MHz.
+    pub const fn interrogation_mhz(&self) -> u16 {
+        1024 + self.number as u16
+    }
+
+    /// The frequency the ground station replies on, 63 MHz above or below the interrogation frequency.
+    pub const fn reply_mhz(&self) -> u16 {
+        let below = match self.mode {
+            DmeMode::X => self.number <= 63,
+            DmeMode::Y => self.number >= 64,
+        };
+        if below {
+            self.interrogation_mhz() - 63
+        } else {
+            self.interrogation_mhz() + 63
+        }
+    }
+
+    /// Spacing between the two pulses of an interrogation pulse pair, in microseconds.
+    pub const fn interrogation_pulse_spacing_us(&self) -> u8 {
+        match self.mode {
+            DmeMode::X => 12,
+            DmeMode::Y => 36,
+        }
+    }
+
+    /// Spacing between the two pulses of a reply pulse pair, in microseconds.
+    pub const fn reply_pulse_spacing_us(&self) -> u8 {
+        match self.mode {
+            DmeMode::X => 12,
+            DmeMode::Y => 30,
+        }
+    }
+}
+
+impl From<NavFrequency> for DmeChannel {
+    fn from(value: NavFrequency) -> Self {
+        DmeChannel::from_nav_frequency(value)
+    }
+}
+
+impl TryFrom<DmeChannel> for NavFrequency {
+    type Error = DmeError;
+    fn try_from(value: DmeChannel) -> Result<Self, Self::Error> {
+        value.nav_frequency().ok_or(DmeError::NotPaired)
+    }
+}
+
+impl std::fmt::Display for DmeChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{:?}", self.number, self.mode)
+    }
+}
+
+impl FromStr for DmeChannel {
+    type Err = DmeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (number, mode) = if let Some(number) = s.strip_suffix(['X', 'x']) {
+            (number, DmeMode::X)
+        } else if let Some(number) = s.strip_suffix(['Y', 'y']) {
+            (number, DmeMode::Y)
+        } else {
+            return Err(DmeError::InvalidMode);
+        };
+        if number.is_empty() || !number.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(DmeError::InvalidNumber);
+        }
+        let number = number.parse::<u8>().map_err(|_| DmeError::InvalidNumber)?;
+        DmeChannel::new(number, mode)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DmeError {
+    InvalidNumber,
+    InvalidMode,
+    NotPaired,
+}
+
+impl std::fmt::Display for DmeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            Self::InvalidNumber => "Channel number outside 1-126",
+            Self::InvalidMode => "Mode is not X or Y",
+            Self::NotPaired => "Channel has no paired VHF frequency",
+        })
+    }
+}
+
+impl std::error::Error for DmeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::RawDmeChannel;
+    use crate::{DmeChannel, DmeError, DmeMode, NavFrequency};
+
+    #[test]
+    fn parse() {
+        assert_eq!("18X".parse::<DmeChannel>().unwrap(), DmeChannel::new(18, DmeMode::X).unwrap());
+        assert_eq!("126y".parse::<DmeChannel>().unwrap(), DmeChannel::new(126, DmeMode::Y).unwrap());
+        assert_eq!("127X".parse::<DmeChannel>(), Err(DmeError::InvalidNumber));
+        assert_eq!("0X".parse::<DmeChannel>(), Err(DmeError::InvalidNumber));
+        assert_eq!("+1X".parse::<DmeChannel>(), Err(DmeError::InvalidNumber));
+        assert_eq!("18".parse::<DmeChannel>(), Err(DmeError::InvalidMode));
+        assert_eq!("".parse::<DmeChannel>(), Err(DmeError::InvalidMode));
+        assert_eq!("1é".parse::<DmeChannel>(), Err(DmeError::InvalidMode));
+        assert_eq!(DmeChannel::new(0, DmeMode::X), Err(DmeError::InvalidNumber));
+    }
+
+    #[test]
+    fn raw() {
+        assert_eq!(DmeChannel::try_from(RawDmeChannel { number: 18, mode: DmeMode::X }), DmeChannel::new(18, DmeMode::X));
+        assert_eq!(DmeChannel::try_from(RawDmeChannel { number: 0, mode: DmeMode::X }), Err(DmeError::InvalidNumber));
+        assert_eq!(DmeChannel::try_from(RawDmeChannel { number: 255, mode: DmeMode::Y }), Err(DmeError::InvalidNumber));
+    }
+
+    #[test]
+    fn nav_pairing() {
+        let pairs = [("17X", "108.00"), ("17Y", "108.05"), ("59Y", "112.25"), ("70X", "112.30"), ("126Y", "117.95")];
+        for (channel, frequency) in pairs {
+            let channel: DmeChannel = channel.parse().unwrap();
+            let frequency: NavFrequency = frequency.parse().unwrap();
+            assert_eq!(channel.nav_frequency(), Some(frequency));
+            assert_eq!(DmeChannel::from(frequency), channel);
+        }
+        assert_eq!("16X".parse::<DmeChannel>().unwrap().nav_frequency(), None);
+        assert_eq!(NavFrequency::try_from("65Y".parse::<DmeChannel>().unwrap()), Err(DmeError::NotPaired));
+    }
+
+    #[test]
+    fn signal() {
+        let channel: DmeChannel = "1X".parse().unwrap();
+        assert_eq!((channel.interrogation_mhz(), channel.reply_mhz()), (1025, 962));
+        let channel: DmeChannel = "63Y".parse().unwrap();
+        assert_eq!((channel.interrogation_mhz(), channel.reply_mhz()), (1087, 1150));
+        let channel: DmeChannel = "64Y".parse().unwrap();
+        assert_eq!((channel.interrogation_mhz(), channel.reply_mhz()), (1088, 1025));
+        let channel: DmeChannel = "126X".parse().unwrap();
+        assert_eq!((channel.interrogation_mhz(), channel.reply_mhz()), (1150, 1213));
+        assert_eq!((channel.interrogation_pulse_spacing_us(), channel.reply_pulse_spacing_us()), (12, 12));
+        let channel: DmeChannel = "40Y".parse().unwrap();
+        assert_eq!((channel.interrogation_pulse_spacing_us(), channel.reply_pulse_spacing_us()), (36, 30));
+    }
+}
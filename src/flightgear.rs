@@ -0,0 +1,123 @@
+//! FlightGear COM radio properties.
+//!
+//! `/instrumentation/comm[n]/frequencies/selected-mhz` is a floating point carrier frequency, and the radio's
+//! channel mode says whether it is on the 25 kHz or the 8.33 kHz grid. Importing rounds to the nearest
+//! carrier on that grid, so 118.00833 and 118.0083 both give the 118.010 channel in 8.33 kHz mode.
+
+use crate::{ChannelFilter, ChannelSpacing, RadioFrequency};
+
+/// How close two channels have to be to the value, in Hz, for it to count as ambiguous between them.
+const AMBIGUITY_HZ: f64 = 1.0;
+
+/// The carrier in MHz.
+pub fn to_selected_mhz(frequency: RadioFrequency) -> f64 {
+    frequency.carrier_hz() as f64 / 1_000_000.0
+}
+
+/// Rounds to the channel on the `spacing` grid with the nearest carrier. Fails if the value is more than half
+/// a channel away from any channel, or about equally close to two of them.
+pub fn from_selected_mhz(mhz: f64, spacing: ChannelSpacing) -> Result<RadioFrequency, FlightGearError> {
+    let hz = mhz * 1_000_000.0;
+    if !hz.is_finite() || hz < 0.0 || hz > u32::MAX as f64 {
+        return Err(FlightGearError::NotFinite);
+    }
+    let (filter, half_step_hz) = match spacing {
+        ChannelSpacing::Khz25 => (ChannelFilter::Khz25, 12_500.0),
+        ChannelSpacing::Khz8_33 => (ChannelFilter::Khz8_33, 25_000.0 / 6.0),
+    };
+
+    // Every carrier within half a 25 kHz step is named within 30 kHz of it.
+    let khz = (hz / 1000.0) as u32;
+    let mut candidates: Vec<(f64, RadioFrequency)> = (khz.saturating_sub(30)..khz + 30)
+        .filter_map(|khz| RadioFrequency::from_khz(khz).ok())
+        .filter(|frequency| filter.matches(frequency))
+        .map(|frequency| ((frequency.carrier_hz() as f64 - hz).abs(), frequency))
+        .filter(|(distance, _)| *distance <= half_step_hz + AMBIGUITY_HZ)
+        .collect();
+    candidates.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    match candidates[..] {
+        [] => Err(FlightGearError::NoChannel),
+        [(nearest, a), (next, b), ..] if next - nearest < AMBIGUITY_HZ => Err(FlightGearError::Ambiguous(a, b)),
+        [(_, frequency), ..] => Ok(frequency),
+    }
+}
+
+/// The channel spacing property value in kHz.
+pub fn spacing_to_khz(spacing: ChannelSpacing) -> f64 {
+    match spacing {
+        ChannelSpacing::Khz25 => 25.0,
+        ChannelSpacing::Khz8_33 => 8.33,
+    }
+}
+
+/// Takes 25, or anything from 8.3 to 8.34 for 8.33 kHz spacing.
+pub fn spacing_from_khz(khz: f64) -> Result<ChannelSpacing, FlightGearError> {
+    if (khz - 25.0).abs() < 0.01 {
+        Ok(ChannelSpacing::Khz25)
+    } else if (8.3..=8.34).contains(&khz) {
+        Ok(ChannelSpacing::Khz8_33)
+    } else {
+        Err(FlightGearError::UnknownSpacing)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FlightGearError {
+    NotFinite,
+    NoChannel,
+    Ambiguous(RadioFrequency, RadioFrequency),
+    UnknownSpacing,
+}
+
+impl std::fmt::Display for FlightGearError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFinite => write!(f, "Not a usable frequency value"),
+            Self::NoChannel => write!(f, "No channel near the value"),
+            Self::Ambiguous(a, b) => write!(f, "Ambiguous between {a} and {b}"),
+            Self::UnknownSpacing => write!(f, "Unknown channel spacing"),
+        }
+    }
+}
+
+impl std::error::Error for FlightGearError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::freq;
+
+    #[test]
+    fn import() {
+        assert_eq!(from_selected_mhz(121.5, ChannelSpacing::Khz25), Ok(freq!("121.500")));
+        assert_eq!(from_selected_mhz(121.50001, ChannelSpacing::Khz25), Ok(freq!("121.500")));
+        assert_eq!(from_selected_mhz(121.51, ChannelSpacing::Khz25), Ok(freq!("121.500")));
+        assert_eq!(from_selected_mhz(118.00833, ChannelSpacing::Khz8_33), Ok(freq!("118.010")));
+        assert_eq!(from_selected_mhz(118.0083, ChannelSpacing::Khz8_33), Ok(freq!("118.010")));
+        assert_eq!(from_selected_mhz(118.0, ChannelSpacing::Khz8_33), Ok(freq!("118.005")));
+        assert_eq!(from_selected_mhz(136.99, ChannelSpacing::Khz8_33), Ok(freq!("136.990")));
+        assert_eq!(
+            from_selected_mhz(121.5125, ChannelSpacing::Khz25),
+            Err(FlightGearError::Ambiguous(freq!("121.500"), freq!("121.525")))
+        );
+        assert_eq!(from_selected_mhz(117.9, ChannelSpacing::Khz25), Err(FlightGearError::NoChannel));
+        assert_eq!(from_selected_mhz(f64::NAN, ChannelSpacing::Khz25), Err(FlightGearError::NotFinite));
+    }
+
+    #[test]
+    fn round_trip() {
+        for spacing in [ChannelSpacing::Khz25, ChannelSpacing::Khz8_33] {
+            let filter = match spacing {
+                ChannelSpacing::Khz25 => ChannelFilter::Khz25,
+                ChannelSpacing::Khz8_33 => ChannelFilter::Khz8_33,
+            };
+            for frequency in RadioFrequency::channels(filter) {
+                assert_eq!(from_selected_mhz(to_selected_mhz(frequency), spacing), Ok(frequency));
+            }
+        }
+        assert_eq!(spacing_from_khz(spacing_to_khz(ChannelSpacing::Khz8_33)), Ok(ChannelSpacing::Khz8_33));
+        assert_eq!(spacing_from_khz(25.0), Ok(ChannelSpacing::Khz25));
+        assert_eq!(spacing_from_khz(12.5), Err(FlightGearError::UnknownSpacing));
+    }
+}
@@ -0,0 +1,359 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    decimal, HfFrequency, HfFrequencyError, MarineChannel, MarineChannelError, NavFrequency, NavFrequencyError, NdbFrequency, NdbFrequencyError,
+    RadioFrequency, RadioFrequencyError, UhfFrequency, ADF_BAND_KHZ, BAND_LEFT, HF_BAND_KHZ, NAV_BAND_LEFT,
+    UHF_BAND_LEFT,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Band {
+    /// VHF COM, 118 to 137 MHz.
+    Com,
+    /// VHF NAV, 108 to 118 MHz.
+    Nav,
+    /// Military UHF, 225 to 400 MHz.
+    Uhf,
+    /// HF, 2.8 to 22 MHz.
+    Hf,
+    /// LF/MF for ADF, 190 to 1750 kHz.
+    Ndb,
+    /// Marine VHF, 156 to 162.025 MHz.
+    Marine,
+}
+
+impl Band {
+    /// Every value in Hz a frequency of this band can report, including 8.33 kHz carriers.
+    pub const fn hz_range(&self) -> std::ops::RangeInclusive<u32> {
+        match self {
+            Self::Com => 118_000_000..=136_991_667,
+            Self::Nav => 108_000_000..=117_950_000,
+            Self::Uhf => 225_000_000..=399_991_667,
+            Self::Hf => 2_800_000..=22_000_000,
+            Self::Ndb => 190_000..=1_750_000,
+            Self::Marine => 156_000_000..=162_025_000,
+        }
+    }
+}
+
+/// What generic code needs to know about a frequency of any band.
+pub trait AviationFrequency {
+    /// The frequency in Hz. For COM and UHF this is the channel name, not the carrier.
+    fn hz(&self) -> u32;
+
+    /// The actual carrier frequency in Hz. Only differs from [`AviationFrequency::hz`] for 8.33 kHz channels.
+    fn carrier_hz(&self) -> u32 {
+        self.hz()
+    }
+
+    fn band(&self) -> Band;
+
+    /// The channel spacing in Hz. 8.33 kHz spacing is reported as 8333.
+    fn spacing_hz(&self) -> u32;
+}
+
+impl AviationFrequency for RadioFrequency {
+    fn hz(&self) -> u32 {
+        RadioFrequency::hz(self)
+    }
+
+    fn carrier_hz(&self) -> u32 {
+        RadioFrequency::carrier_hz(self)
+    }
+
+    fn band(&self) -> Band {
+        Band::Com
+    }
+
+    fn spacing_hz(&self) -> u32 {
+        if self.is_25_khz_spaced() { 25_000 } else { 8_333 }
+    }
+}
+
+impl AviationFrequency for UhfFrequency {
+    fn hz(&self) -> u32 {
+        UhfFrequency::hz(self)
+    }
+
+    fn carrier_hz(&self) -> u32 {
+        UhfFrequency::carrier_hz(self)
+    }
+
+    fn band(&self) -> Band {
+        Band::Uhf
+    }
+
+    fn spacing_hz(&self) -> u32 {
+        if self.is_25_khz_spaced() { 25_000 } else { 8_333 }
+    }
+}
+
+impl AviationFrequency for NavFrequency {
+    fn hz(&self) -> u32 {
+        NavFrequency::hz(self)
+    }
+
+    fn band(&self) -> Band {
+        Band::Nav
+    }
+
+    fn spacing_hz(&self) -> u32 {
+        50_000
+    }
+}
+
+impl AviationFrequency for HfFrequency {
+    fn hz(&self) -> u32 {
+        HfFrequency::hz(self)
+    }
+
+    fn band(&self) -> Band {
+        Band::Hf
+    }
+
+    fn spacing_hz(&self) -> u32 {
+        1_000
+    }
+}
+
+impl AviationFrequency for NdbFrequency {
+    fn hz(&self) -> u32 {
+        NdbFrequency::hz(self)
+    }
+
+    fn band(&self) -> Band {
+        Band::Ndb
+    }
+
+    fn spacing_hz(&self) -> u32 {
+        500
+    }
+}
+
+/// A frequency in any of the aviation bands this crate models.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Frequency {
+    Com(RadioFrequency),
+    Nav(NavFrequency),
+    Uhf(UhfFrequency),
+    Hf(HfFrequency),
+    Ndb(NdbFrequency),
+    Marine(MarineChannel),
+}
+
+impl Frequency {
+    fn inner(&self) -> &dyn AviationFrequency {
+        match self {
+            Self::Com(frequency) => frequency,
+            Self::Nav(frequency) => frequency,
+            Self::Uhf(frequency) => frequency,
+            Self::Hf(frequency) => frequency,
+            Self::Ndb(frequency) => frequency,
+            Self::Marine(channel) => channel,
+        }
+    }
+}
+
+impl AviationFrequency for Frequency {
+    fn hz(&self) -> u32 {
+        self.inner().hz()
+    }
+
+    fn carrier_hz(&self) -> u32 {
+        self.inner().carrier_hz()
+    }
+
+    fn band(&self) -> Band {
+        self.inner().band()
+    }
+
+    fn spacing_hz(&self) -> u32 {
+        self.inner().spacing_hz()
+    }
+}
+
+impl From<RadioFrequency> for Frequency {
+    fn from(value: RadioFrequency) -> Self {
+        Self::Com(value)
+    }
+}
+
+impl From<NavFrequency> for Frequency {
+    fn from(value: NavFrequency) -> Self {
+        Self::Nav(value)
+    }
+}
+
+impl From<UhfFrequency> for Frequency {
+    fn from(value: UhfFrequency) -> Self {
+        Self::Uhf(value)
+    }
+}
+
+impl From<HfFrequency> for Frequency {
+    fn from(value: HfFrequency) -> Self {
+        Self::Hf(value)
+    }
+}
+
+impl From<NdbFrequency> for Frequency {
+    fn from(value: NdbFrequency) -> Self {
+        Self::Ndb(value)
+    }
+}
+
+impl From<MarineChannel> for Frequency {
+    fn from(value: MarineChannel) -> Self {
+        Self::Marine(value)
+    }
+}
+
+impl std::fmt::Display for Frequency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Com(frequency) => frequency.fmt(f),
+            Self::Nav(frequency) => frequency.fmt(f),
+            Self::Uhf(frequency) => frequency.fmt(f),
+            Self::Hf(frequency) => frequency.fmt(f),
+            Self::Ndb(frequency) => frequency.fmt(f),
+            Self::Marine(channel) => write!(f, "CH {channel}"),
+        }
+    }
+}
+
+/// Infers the band from the number. Values with a dot in 108-117, 118-136 or 225-399 are MHz (NAV, COM, UHF).
+/// Whole numbers in 190-1750 are NDB kHz, as are 1 decimal values outside the MHz ranges, and whole numbers
+/// in 2800-22000 are HF kHz. So "375" is an NDB but "375.5" is UHF. Marine channel numbers clash with these
+/// forms, so marine channels take the "CH " prefix that `Display` writes, as in "CH 16" or "CH 87B".
+impl FromStr for Frequency {
+    type Err = FrequencyError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(channel) = s.strip_prefix("CH ") {
+            return Ok(Self::Marine(channel.parse()?));
+        }
+        let (whole, _) = decimal::parse_decimal(s, 3, false).map_err(|_| FrequencyError::InvalidFormat)?;
+        let has_dot = s.contains('.');
+        let in_range = |range: std::ops::RangeInclusive<u16>| u16::try_from(whole).is_ok_and(|whole| range.contains(&whole));
+
+        if has_dot && in_range(NAV_BAND_LEFT) {
+            Ok(Self::Nav(s.parse()?))
+        } else if has_dot && in_range(BAND_LEFT) {
+            Ok(Self::Com(s.parse()?))
+        } else if has_dot && in_range(UHF_BAND_LEFT) {
+            Ok(Self::Uhf(s.parse().map_err(FrequencyError::Uhf)?))
+        } else if in_range(ADF_BAND_KHZ) {
+            Ok(Self::Ndb(s.parse()?))
+        } else if !has_dot && in_range(HF_BAND_KHZ) {
+            Ok(Self::Hf(s.parse()?))
+        } else {
+            Err(FrequencyError::UnknownBand)
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FrequencyError {
+    InvalidFormat,
+    UnknownBand,
+    Com(RadioFrequencyError),
+    Nav(NavFrequencyError),
+    Uhf(RadioFrequencyError),
+    Hf(HfFrequencyError),
+    Ndb(NdbFrequencyError),
+    Marine(MarineChannelError),
+}
+
+impl From<RadioFrequencyError> for FrequencyError {
+    fn from(value: RadioFrequencyError) -> Self {
+        Self::Com(value)
+    }
+}
+
+impl From<NavFrequencyError> for FrequencyError {
+    fn from(value: NavFrequencyError) -> Self {
+        Self::Nav(value)
+    }
+}
+
+impl From<HfFrequencyError> for FrequencyError {
+    fn from(value: HfFrequencyError) -> Self {
+        Self::Hf(value)
+    }
+}
+
+impl From<NdbFrequencyError> for FrequencyError {
+    fn from(value: NdbFrequencyError) -> Self {
+        Self::Ndb(value)
+    }
+}
+
+impl From<MarineChannelError> for FrequencyError {
+    fn from(value: MarineChannelError) -> Self {
+        Self::Marine(value)
+    }
+}
+
+impl std::fmt::Display for FrequencyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidFormat => write!(f, "Not a number"),
+            Self::UnknownBand => write!(f, "Not in any aviation band"),
+            Self::Com(error) | Self::Uhf(error) => write!(f, "{error}"),
+            Self::Nav(error) => write!(f, "{error}"),
+            Self::Hf(error) => write!(f, "{error}"),
+            Self::Ndb(error) => write!(f, "{error}"),
+            Self::Marine(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for FrequencyError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AviationFrequency, Band, Frequency, FrequencyError, MarineChannel, MarineChannelError, NavFrequencyError};
+
+    #[test]
+    fn infer_band() {
+        let cases = [
+            ("110.30", Band::Nav, 110_300_000),
+            ("121.5", Band::Com, 121_500_000),
+            ("243.000", Band::Uhf, 243_000_000),
+            ("375", Band::Ndb, 375_000),
+            ("415.5", Band::Ndb, 415_500),
+            ("8891", Band::Hf, 8_891_000),
+        ];
+        for (s, band, hz) in cases {
+            let frequency: Frequency = s.parse().unwrap();
+            assert_eq!(frequency.band(), band, "{s}");
+            assert_eq!(frequency.hz(), hz, "{s}");
+        }
+        assert_eq!("375.5".parse::<Frequency>().unwrap().band(), Band::Uhf);
+        assert_eq!("110.32".parse::<Frequency>(), Err(FrequencyError::Nav(NavFrequencyError::NotAChannel)));
+        assert_eq!("150.000".parse::<Frequency>(), Err(FrequencyError::UnknownBand));
+        assert_eq!("2000".parse::<Frequency>(), Err(FrequencyError::UnknownBand));
+        assert_eq!("abc".parse::<Frequency>(), Err(FrequencyError::InvalidFormat));
+    }
+
+    #[test]
+    fn generic() {
+        let frequency: Frequency = "118.010".parse().unwrap();
+        assert_eq!(frequency.to_string(), "118.010");
+        assert_eq!(frequency.hz(), 118_010_000);
+        assert_eq!(frequency.carrier_hz(), 118_008_333);
+        assert_eq!(frequency.spacing_hz(), 8_333);
+        assert_eq!("8891".parse::<Frequency>().unwrap().spacing_hz(), 1_000);
+        assert_eq!("113.85".parse::<Frequency>().unwrap().carrier_hz(), 113_850_000);
+        assert!(frequency.band().hz_range().contains(&frequency.carrier_hz()));
+
+        let marine = Frequency::from(MarineChannel::DISTRESS);
+        assert_eq!(marine.band(), Band::Marine);
+        assert_eq!(marine.hz(), 156_800_000);
+        assert_eq!(marine.to_string(), "CH 16");
+        assert_eq!(marine.to_string().parse::<Frequency>(), Ok(marine));
+        assert_eq!("CH 87B".parse::<Frequency>().unwrap().to_string(), "CH 87B");
+        assert_eq!("CH 16A".parse::<Frequency>(), Err(FrequencyError::Marine(MarineChannelError::SuffixOnSimplex)));
+    }
+}
@@ -0,0 +1,100 @@
+//! Frequencies as VATSIM and IVAO FSD packets carry them: five digits without the leading "1" and the dot,
+//! so 122.800 is "22800". Text messages address a frequency as "@22800", and several as "@22800&@21500".
+//!
+//! Some clients send an 8.33 kHz channel as its carrier cut to the kHz, e.g. "18008" for 118.010. Decoding
+//! reconstructs the channel name from those.
+
+use crate::{RadioFrequency, RadioFrequencyError};
+
+pub fn encode(frequency: RadioFrequency) -> String {
+    format!("{:05}", frequency.khz() - 100_000)
+}
+
+pub fn decode(s: &str) -> Result<RadioFrequency, FsdError> {
+    if s.len() != 5 {
+        return Err(FsdError::InvalidLength);
+    }
+    if !s.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(FsdError::InvalidCharacter);
+    }
+    let khz = 100_000 + s.parse::<u32>().map_err(|_| FsdError::InvalidCharacter)?;
+    Ok(RadioFrequency::from_khz_or_carrier_khz(khz)?)
+}
+
+/// "@22800" for 122.800.
+pub fn encode_recipient(frequency: RadioFrequency) -> String {
+    format!("@{}", encode(frequency))
+}
+
+pub fn decode_recipient(s: &str) -> Result<RadioFrequency, FsdError> {
+    decode(s.strip_prefix('@').ok_or(FsdError::MissingPrefix)?)
+}
+
+/// "@22800&@21500" for 122.800 and 121.500.
+pub fn encode_recipients(frequencies: &[RadioFrequency]) -> String {
+    frequencies.iter().map(|&frequency| encode_recipient(frequency)).collect::<Vec<_>>().join("&")
+}
+
+pub fn decode_recipients(s: &str) -> Result<Vec<RadioFrequency>, FsdError> {
+    s.split('&').map(decode_recipient).collect()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FsdError {
+    MissingPrefix,
+    InvalidLength,
+    InvalidCharacter,
+    Frequency(RadioFrequencyError),
+}
+
+impl From<RadioFrequencyError> for FsdError {
+    fn from(value: RadioFrequencyError) -> Self {
+        Self::Frequency(value)
+    }
+}
+
+impl std::fmt::Display for FsdError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingPrefix => write!(f, "Missing @ prefix"),
+            Self::InvalidLength => write!(f, "Not five digits"),
+            Self::InvalidCharacter => write!(f, "Invalid character"),
+            Self::Frequency(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for FsdError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::freq;
+
+    #[test]
+    fn single() {
+        assert_eq!(encode(freq!("122.800")), "22800");
+        assert_eq!(encode(freq!("118.005")), "18005");
+        assert_eq!(decode("22800"), Ok(freq!("122.800")));
+        assert_eq!(decode("32605"), Ok(freq!("132.605")));
+        assert_eq!(decode("18008"), Ok(freq!("118.010")));
+        assert_eq!(decode("18016"), Ok(freq!("118.015")));
+        assert_eq!(decode("2280"), Err(FsdError::InvalidLength));
+        assert_eq!(decode("+2280"), Err(FsdError::InvalidCharacter));
+        assert_eq!(decode("22820"), Err(FsdError::Frequency(RadioFrequencyError::NotAChannel)));
+        assert_eq!(decode("40000"), Err(FsdError::Frequency(RadioFrequencyError::LeftOutOfBand)));
+    }
+
+    #[test]
+    fn recipients() {
+        assert_eq!(encode_recipient(freq!("122.800")), "@22800");
+        assert_eq!(decode_recipient("@22800"), Ok(freq!("122.800")));
+        assert_eq!(decode_recipient("22800"), Err(FsdError::MissingPrefix));
+
+        let frequencies = [freq!("122.800"), freq!("121.500")];
+        assert_eq!(encode_recipients(&frequencies), "@22800&@21500");
+        assert_eq!(decode_recipients("@22800&@21500"), Ok(frequencies.to_vec()));
+        assert_eq!(decode_recipients("@22800&21500"), Err(FsdError::MissingPrefix));
+        assert_eq!(decode_recipients(""), Err(FsdError::MissingPrefix));
+    }
+}
@@ -0,0 +1,272 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::decimal::{self, DecimalError};
+
+/// The kHz range of the HF aeronautical type, 2.8 to 22 MHz.
+pub const HF_BAND_KHZ: std::ops::RangeInclusive<u16> = 2800..=22000;
+
+/// An HF frequency in whole kHz between 2.8 and 22 MHz. Serializes as a number of kHz.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "u16", into = "u16")]
+pub struct HfFrequency {
+    khz: u16,
+}
+
+/// The aeronautical mobile (R) bands of ITU Radio Regulations Appendix 27, named by their usual MHz label.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum HfBand {
+    Mhz3,
+    Mhz3_4,
+    Mhz4_7,
+    Mhz5_6,
+    Mhz6_6,
+    Mhz8_9,
+    Mhz10,
+    Mhz11_3,
+    Mhz13_3,
+    Mhz17_9,
+    Mhz22,
+}
+
+impl HfBand {
+    pub const ALL: [HfBand; 11] = [
+        Self::Mhz3,
+        Self::Mhz3_4,
+        Self::Mhz4_7,
+        Self::Mhz5_6,
+        Self::Mhz6_6,
+        Self::Mhz8_9,
+        Self::Mhz10,
+        Self::Mhz11_3,
+        Self::Mhz13_3,
+        Self::Mhz17_9,
+        Self::Mhz22,
+    ];
+
+    pub const fn khz_range(&self) -> std::ops::RangeInclusive<u16> {
+        match self {
+            Self::Mhz3 => 2850..=3025,
+            Self::Mhz3_4 => 3400..=3500,
+            Self::Mhz4_7 => 4650..=4700,
+            Self::Mhz5_6 => 5450..=5680,
+            Self::Mhz6_6 => 6525..=6685,
+            Self::Mhz8_9 => 8815..=8965,
+            Self::Mhz10 => 10005..=10100,
+            Self::Mhz11_3 => 11275..=11400,
+            Self::Mhz13_3 => 13260..=13360,
+            Self::Mhz17_9 => 17900..=17970,
+            Self::Mhz22 => 21924..=22000,
+        }
+    }
+}
+
+/// Single sideband mode. Appendix 27 requires upper sideband on the aeronautical mobile (R) bands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Sideband {
+    Usb,
+    Lsb,
+}
+
+/// North Atlantic HF frequency families.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum HfFamily {
+    NatA,
+    NatB,
+    NatC,
+    NatD,
+    NatE,
+    NatF,
+}
+
+const fn hf(khz: u16) -> HfFrequency {
+    match HfFrequency::from_khz(khz) {
+        Ok(frequency) => frequency,
+        Err(_) => panic!("invalid HF frequency"),
+    }
+}
+
+const NAT_A: [HfFrequency; 5] = [hf(3016), hf(5598), hf(8906), hf(13306), hf(17946)];
+const NAT_B: [HfFrequency; 5] = [hf(2899), hf(5616), hf(8864), hf(13291), hf(17946)];
+const NAT_C: [HfFrequency; 6] = [hf(2872), hf(5649), hf(8879), hf(11336), hf(13306), hf(17946)];
+const NAT_D: [HfFrequency; 6] = [hf(2971), hf(4675), hf(8891), hf(11279), hf(13291), hf(17946)];
+const NAT_E: [HfFrequency; 6] = [hf(2962), hf(6628), hf(8825), hf(11309), hf(13354), hf(17946)];
+const NAT_F: [HfFrequency; 6] = [hf(3476), hf(6622), hf(8831), hf(13291), hf(17946), hf(21964)];
+
+impl HfFamily {
+    pub const ALL: [HfFamily; 6] = [Self::NatA, Self::NatB, Self::NatC, Self::NatD, Self::NatE, Self::NatF];
+
+    pub const fn frequencies(&self) -> &'static [HfFrequency] {
+        match self {
+            Self::NatA => &NAT_A,
+            Self::NatB => &NAT_B,
+            Self::NatC => &NAT_C,
+            Self::NatD => &NAT_D,
+            Self::NatE => &NAT_E,
+            Self::NatF => &NAT_F,
+        }
+    }
+
+    pub fn contains(&self, frequency: &HfFrequency) -> bool {
+        self.frequencies().contains(frequency)
+    }
+}
+
+impl HfFrequency {
+    pub const fn from_khz(khz: u16) -> Result<HfFrequency, HfFrequencyError> {
+        if khz < *HF_BAND_KHZ.start() || khz > *HF_BAND_KHZ.end() {
+            return Err(HfFrequencyError::OutOfBand);
+        }
+        Ok(HfFrequency { khz })
+    }
+
+    /// Parses whole kHz such as "8891" in const context, with the same rules as the `FromStr` impl.
+    pub const fn parse(s: &str) -> Result<HfFrequency, HfFrequencyError> {
+        match decimal::parse_decimal(s, 0, false) {
+            Ok((khz, _)) if khz <= u16::MAX as u32 => HfFrequency::from_khz(khz as u16),
+            Ok(_) => Err(HfFrequencyError::OutOfBand),
+            Err(error) => Err(HfFrequencyError::from_decimal(error)),
+        }
+    }
+
+    pub const fn khz(&self) -> u16 {
+        self.khz
+    }
+
+    pub const fn hz(&self) -> u32 {
+        self.khz as u32 * 1000
+    }
+
+    /// The Appendix 27 band this frequency is in, if any.
+    pub fn band(&self) -> Option<HfBand> {
+        HfBand::ALL.into_iter().find(|band| band.khz_range().contains(&self.khz))
+    }
+
+    /// The North Atlantic families this frequency belongs to.
+    pub fn families(&self) -> Vec<HfFamily> {
+        HfFamily::ALL.into_iter().filter(|family| family.contains(self)).collect()
+    }
+}
+
+/// An HF frequency together with the sideband it is worked on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct HfAssignment {
+    pub frequency: HfFrequency,
+    pub sideband: Sideband,
+}
+
+impl HfAssignment {
+    /// Checks the assignment is in an Appendix 27 band and uses upper sideband.
+    pub fn validate(&self) -> Result<HfBand, HfFrequencyError> {
+        let band = self.frequency.band().ok_or(HfFrequencyError::NotInAeronauticalBand)?;
+        if self.sideband != Sideband::Usb {
+            return Err(HfFrequencyError::LsbNotPermitted);
+        }
+        Ok(band)
+    }
+}
+
+impl TryFrom<u16> for HfFrequency {
+    type Error = HfFrequencyError;
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        HfFrequency::from_khz(value)
+    }
+}
+
+impl From<HfFrequency> for u16 {
+    fn from(value: HfFrequency) -> Self {
+        value.khz
+    }
+}
+
+impl std::fmt::Display for HfFrequency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.khz)
+    }
+}
+
+impl FromStr for HfFrequency {
+    type Err = HfFrequencyError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        HfFrequency::parse(s)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum HfFrequencyError {
+    OutOfBand,
+    NotInAeronauticalBand,
+    LsbNotPermitted,
+    NotWholeKhz,
+    TooManyParts,
+    MissingKhz,
+    UnexpectedSign,
+    UnexpectedWhitespace,
+    InvalidCharacter,
+}
+
+impl HfFrequencyError {
+    const fn from_decimal(error: DecimalError) -> Self {
+        match error {
+            // No decimals are allowed, so any dot means the value is not whole kHz.
+            DecimalError::NotEnoughParts | DecimalError::MissingDecimals | DecimalError::TooManyDecimals => Self::NotWholeKhz,
+            DecimalError::TooManyParts => Self::TooManyParts,
+            DecimalError::MissingWhole => Self::MissingKhz,
+            DecimalError::UnexpectedSign => Self::UnexpectedSign,
+            DecimalError::UnexpectedWhitespace => Self::UnexpectedWhitespace,
+            DecimalError::InvalidCharacter => Self::InvalidCharacter,
+        }
+    }
+}
+
+impl std::fmt::Display for HfFrequencyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            Self::OutOfBand => "Outside 2800-22000 kHz",
+            Self::NotInAeronauticalBand => "Not in an aeronautical mobile (R) band",
+            Self::LsbNotPermitted => "Lower sideband is not permitted",
+            Self::NotWholeKhz => "Not a whole number of kHz",
+            Self::TooManyParts => "Too many parts",
+            Self::MissingKhz => "Missing kHz",
+            Self::UnexpectedSign => "Unexpected sign",
+            Self::UnexpectedWhitespace => "Unexpected whitespace",
+            Self::InvalidCharacter => "Invalid character",
+        })
+    }
+}
+
+impl std::error::Error for HfFrequencyError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{HfAssignment, HfBand, HfFamily, HfFrequency, HfFrequencyError, Sideband};
+
+    #[test]
+    fn parse_and_bands() {
+        let frequency: HfFrequency = "8891".parse().unwrap();
+        assert_eq!(frequency.hz(), 8_891_000);
+        assert_eq!(frequency.to_string(), "8891");
+        assert_eq!(frequency.band(), Some(HfBand::Mhz8_9));
+        assert_eq!("5649".parse::<HfFrequency>().unwrap().band(), Some(HfBand::Mhz5_6));
+        assert_eq!("7000".parse::<HfFrequency>().unwrap().band(), None);
+        assert_eq!("2799".parse::<HfFrequency>(), Err(HfFrequencyError::OutOfBand));
+        assert_eq!("8891.5".parse::<HfFrequency>(), Err(HfFrequencyError::NotWholeKhz));
+        assert_eq!("8891.".parse::<HfFrequency>(), Err(HfFrequencyError::NotWholeKhz));
+    }
+
+    #[test]
+    fn families_and_assignments() {
+        let frequency: HfFrequency = "5649".parse().unwrap();
+        assert_eq!(frequency.families(), [HfFamily::NatC]);
+        assert_eq!("17946".parse::<HfFrequency>().unwrap().families().len(), 6);
+        for family in HfFamily::ALL {
+            assert!(family.frequencies().iter().all(|f| f.band().is_some()));
+        }
+
+        assert_eq!(HfAssignment { frequency, sideband: Sideband::Usb }.validate(), Ok(HfBand::Mhz5_6));
+        assert_eq!(HfAssignment { frequency, sideband: Sideband::Lsb }.validate(), Err(HfFrequencyError::LsbNotPermitted));
+        let frequency = "7000".parse().unwrap();
+        assert_eq!(HfAssignment { frequency, sideband: Sideband::Usb }.validate(), Err(HfFrequencyError::NotInAeronauticalBand));
+    }
+}
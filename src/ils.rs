@@ -0,0 +1,212 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{DmeChannel, NavFrequency, NavFrequencyError};
+
+/// Glideslope frequencies in kHz for each localizer from 108.10 to 111.95, in order, per ICAO Annex 10.
+const GLIDESLOPE_KHZ: [u32; 40] = [
+    334_700, 334_550, 334_100, 333_950, 329_900, 329_750, 330_500, 330_350, 329_300, 329_150,
+    331_400, 331_250, 332_000, 331_850, 332_600, 332_450, 333_200, 333_050, 333_800, 333_650,
+    334_400, 334_250, 335_000, 334_850, 329_600, 329_450, 330_200, 330_050, 330_800, 330_650,
+    331_700, 331_550, 332_300, 332_150, 332_900, 332_750, 333_500, 333_350, 331_100, 330_950,
+];
+
+/// An ILS localizer frequency: one of the odd tenths from 108.10 to 111.95.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "NavFrequency", into = "NavFrequency")]
+pub struct LocalizerFrequency(NavFrequency);
+
+/// A UHF glideslope frequency between 329.15 and 335.00 MHz.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "u32", into = "u32")]
+pub struct GlideslopeFrequency {
+    khz: u32,
+}
+
+impl LocalizerFrequency {
+    pub const fn new(frequency: NavFrequency) -> Result<LocalizerFrequency, IlsError> {
+        if !frequency.is_localizer() {
+            return Err(IlsError::NotALocalizer);
+        }
+        Ok(LocalizerFrequency(frequency))
+    }
+
+    pub const fn nav_frequency(&self) -> NavFrequency {
+        self.0
+    }
+
+    /// Position in the ICAO pairing table, 0 for 108.10 up to 39 for 111.95.
+    const fn pairing_index(&self) -> usize {
+        let khz = self.0.khz();
+        ((khz - 108_100) / 200 * 2 + (khz % 100 / 50)) as usize
+    }
+
+    const fn from_pairing_index(index: usize) -> LocalizerFrequency {
+        let khz = 108_100 + (index / 2) as u32 * 200 + (index % 2) as u32 * 50;
+        match NavFrequency::from_khz(khz) {
+            Ok(frequency) => LocalizerFrequency(frequency),
+            Err(_) => panic!("pairing index out of range"),
+        }
+    }
+
+    pub const fn glideslope(&self) -> GlideslopeFrequency {
+        GlideslopeFrequency {
+            khz: GLIDESLOPE_KHZ[self.pairing_index()],
+        }
+    }
+
+    /// The paired DME channel, 18X for 108.10 up to 56Y for 111.95.
+    pub const fn dme_channel(&self) -> DmeChannel {
+        DmeChannel::from_nav_frequency(self.0)
+    }
+
+    /// The localizer paired with a DME channel, if that channel is an ILS channel.
+    pub const fn from_dme_channel(channel: DmeChannel) -> Option<LocalizerFrequency> {
+        match channel.nav_frequency() {
+            Some(frequency) if frequency.is_localizer() => Some(LocalizerFrequency(frequency)),
+            _ => None,
+        }
+    }
+}
+
+impl GlideslopeFrequency {
+    pub const fn from_khz(khz: u32) -> Result<GlideslopeFrequency, IlsError> {
+        let mut i = 0;
+        while i < GLIDESLOPE_KHZ.len() {
+            if GLIDESLOPE_KHZ[i] == khz {
+                return Ok(GlideslopeFrequency { khz });
+            }
+            i += 1;
+        }
+        Err(IlsError::NotAGlideslope)
+    }
+
+    pub const fn khz(&self) -> u32 {
+        self.khz
+    }
+
+    pub const fn hz(&self) -> u32 {
+        self.khz * 1000
+    }
+
+    /// The localizer this glideslope is paired with.
+    pub const fn localizer(&self) -> LocalizerFrequency {
+        let mut i = 0;
+        while GLIDESLOPE_KHZ[i] != self.khz {
+            i += 1;
+        }
+        LocalizerFrequency::from_pairing_index(i)
+    }
+}
+
+impl TryFrom<NavFrequency> for LocalizerFrequency {
+    type Error = IlsError;
+    fn try_from(value: NavFrequency) -> Result<Self, Self::Error> {
+        LocalizerFrequency::new(value)
+    }
+}
+
+impl From<LocalizerFrequency> for NavFrequency {
+    fn from(value: LocalizerFrequency) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<u32> for GlideslopeFrequency {
+    type Error = IlsError;
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        GlideslopeFrequency::from_khz(value)
+    }
+}
+
+impl From<GlideslopeFrequency> for u32 {
+    fn from(value: GlideslopeFrequency) -> Self {
+        value.khz
+    }
+}
+
+impl std::fmt::Display for LocalizerFrequency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::fmt::Display for GlideslopeFrequency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{:02}", self.khz / 1000, self.khz % 1000 / 10)
+    }
+}
+
+impl FromStr for LocalizerFrequency {
+    type Err = IlsError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        LocalizerFrequency::new(s.parse()?)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum IlsError {
+    NotALocalizer,
+    NotAGlideslope,
+    Nav(NavFrequencyError),
+}
+
+impl From<NavFrequencyError> for IlsError {
+    fn from(value: NavFrequencyError) -> Self {
+        Self::Nav(value)
+    }
+}
+
+impl std::fmt::Display for IlsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotALocalizer => write!(f, "Not a localizer frequency"),
+            Self::NotAGlideslope => write!(f, "Not a glideslope frequency"),
+            Self::Nav(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for IlsError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DmeChannel, DmeMode, GlideslopeFrequency, IlsError, LocalizerFrequency};
+
+    #[test]
+    fn pairing() {
+        let localizer: LocalizerFrequency = "110.30".parse().unwrap();
+        assert_eq!(localizer.glideslope().to_string(), "335.00");
+        assert_eq!(localizer.dme_channel(), DmeChannel::new(40, DmeMode::X).unwrap());
+
+        let localizer: LocalizerFrequency = "108.15".parse().unwrap();
+        assert_eq!(localizer.glideslope().khz(), 334_550);
+        assert_eq!(localizer.dme_channel().to_string(), "18Y");
+
+        let localizer: LocalizerFrequency = "111.95".parse().unwrap();
+        assert_eq!(localizer.glideslope().to_string(), "330.95");
+        assert_eq!(localizer.dme_channel().to_string(), "56Y");
+
+        assert_eq!("110.40".parse::<LocalizerFrequency>(), Err(IlsError::NotALocalizer));
+        assert_eq!(GlideslopeFrequency::from_khz(329_200), Err(IlsError::NotAGlideslope));
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut glideslopes = Vec::new();
+        for index in 0..40 {
+            let localizer = LocalizerFrequency::from_pairing_index(index);
+            assert_eq!(localizer.pairing_index(), index);
+            assert!(localizer.nav_frequency().is_localizer());
+            assert_eq!(localizer.glideslope().localizer(), localizer);
+            assert_eq!(LocalizerFrequency::from_dme_channel(localizer.dme_channel()), Some(localizer));
+            glideslopes.push(localizer.glideslope().khz());
+        }
+        glideslopes.sort();
+        let grid: Vec<u32> = (0..40).map(|i| 329_150 + i * 150).collect();
+        assert_eq!(glideslopes, grid);
+        assert_eq!(LocalizerFrequency::from_dme_channel(DmeChannel::new(19, DmeMode::X).unwrap()), None);
+        assert_eq!(LocalizerFrequency::from_dme_channel(DmeChannel::new(58, DmeMode::X).unwrap()), None);
+    }
+}
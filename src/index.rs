@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{RadioFrequency, RadioFrequencyError, CHANNEL_COUNT};
+
+/// Dense index of a channel in the ordered list of every channel name in the band,
+/// from 0 for 118.000 to 3039 for 136.990. Usable directly as an array index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "u16", into = "u16")]
+pub struct ChannelIndex(pub(crate) u16);
+
+impl ChannelIndex {
+    /// The number of channels in the band, and so one past the highest index.
+    pub const COUNT: u16 = CHANNEL_COUNT;
+
+    pub const fn new(index: u16) -> Result<ChannelIndex, RadioFrequencyError> {
+        if index >= Self::COUNT {
+            return Err(RadioFrequencyError::IndexOutOfRange);
+        }
+        Ok(ChannelIndex(index))
+    }
+
+    pub const fn get(&self) -> u16 {
+        self.0
+    }
+}
+
+impl TryFrom<u16> for ChannelIndex {
+    type Error = RadioFrequencyError;
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        ChannelIndex::new(value)
+    }
+}
+
+impl From<ChannelIndex> for u16 {
+    fn from(value: ChannelIndex) -> Self {
+        value.0
+    }
+}
+
+impl From<ChannelIndex> for usize {
+    fn from(value: ChannelIndex) -> Self {
+        value.0 as usize
+    }
+}
+
+impl From<RadioFrequency> for ChannelIndex {
+    fn from(value: RadioFrequency) -> Self {
+        value.index()
+    }
+}
+
+impl From<ChannelIndex> for RadioFrequency {
+    fn from(value: ChannelIndex) -> Self {
+        RadioFrequency::from_ordinal(value.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ChannelIndex, RadioFrequency, RadioFrequencyError};
+
+    #[test]
+    fn round_trip() {
+        for index in 0..ChannelIndex::COUNT {
+            let index = ChannelIndex::new(index).unwrap();
+            let frequency = RadioFrequency::from(index);
+            assert_eq!(RadioFrequency::new(frequency.left(), frequency.right()).unwrap(), frequency);
+            assert_eq!(ChannelIndex::from(frequency), index);
+        }
+        assert_eq!(ChannelIndex::new(ChannelIndex::COUNT), Err(RadioFrequencyError::IndexOutOfRange));
+        assert_eq!(RadioFrequency::new(118, 0).unwrap().index().get(), 0);
+        assert_eq!(RadioFrequency::new(136, 990).unwrap().index().get(), ChannelIndex::COUNT - 1);
+    }
+}
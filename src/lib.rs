@@ -1,62 +1,323 @@
 use std::{str::FromStr, num::ParseIntError};
 use serde::{Serialize, Deserialize};
+use decimal::DecimalError;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub mod afv;
+mod capability;
+mod channels;
+mod collections;
+mod decimal;
+mod dme;
+pub mod flightgear;
+mod frequency;
+pub mod fsd;
+mod hf;
+mod ils;
+mod index;
+mod marine;
+pub mod msfs;
+mod nav;
+mod ndb;
+mod policy;
+mod tuning;
+mod uhf;
+pub mod well_known;
+pub mod xplane;
+
+pub use capability::RadioCapability;
+pub use channels::Channels;
+pub use collections::{FrequencyRange, FrequencySet};
+pub use dme::{DmeChannel, DmeError, DmeMode};
+pub use frequency::{AviationFrequency, Band, Frequency, FrequencyError};
+pub use hf::{HfAssignment, HfBand, HfFamily, HfFrequency, HfFrequencyError, Sideband, HF_BAND_KHZ};
+pub use ils::{GlideslopeFrequency, IlsError, LocalizerFrequency};
+pub use index::ChannelIndex;
+pub use marine::{MarineChannel, MarineChannelError, MarineSuffix};
+pub use nav::{NavChannelKind, NavFrequency, NavFrequencyError, NAV_BAND_LEFT};
+pub use ndb::{NdbFrequency, NdbFrequencyError, ADF_BAND_KHZ, BROADCAST_BAND_KHZ, NDB_BAND_KHZ};
+pub use policy::{SpacingPolicy, SpacingViolation, EUR_8_33_FLIGHT_LEVEL};
+pub use tuning::ChannelFilter;
+pub use uhf::{PairedFrequency, UhfFrequency, UHF_BAND_LEFT};
+pub use well_known::FrequencyRole;
+
+/// A VHF COM channel, stored as its [`ChannelIndex`] so it fits in a `u16`.
+/// Serializes as `left`, `right` and `is_25_khz_spaced` fields.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(into = "RawRadioFrequency", try_from = "RawRadioFrequency")]
 pub struct RadioFrequency {
+    index: ChannelIndex,
+}
+
+#[derive(Serialize, Deserialize)]
+struct RawRadioFrequency {
     left: u16,
     right: u16,
     is_25_khz_spaced: bool,
 }
 
+impl From<RadioFrequency> for RawRadioFrequency {
+    fn from(value: RadioFrequency) -> Self {
+        RawRadioFrequency {
+            left: value.left(),
+            right: value.right(),
+            is_25_khz_spaced: value.is_25_khz_spaced(),
+        }
+    }
+}
+
+impl TryFrom<RawRadioFrequency> for RadioFrequency {
+    type Error = RadioFrequencyError;
+    fn try_from(value: RawRadioFrequency) -> Result<Self, Self::Error> {
+        let frequency = RadioFrequency::new(value.left, value.right)?;
+        if frequency.is_25_khz_spaced() != value.is_25_khz_spaced {
+            return Err(RadioFrequencyError::InvalidFrequency);
+        }
+        Ok(frequency)
+    }
+}
+
+impl std::fmt::Debug for RadioFrequency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RadioFrequency")
+            .field("left", &self.left())
+            .field("right", &self.right())
+            .field("is_25_khz_spaced", &self.is_25_khz_spaced())
+            .finish()
+    }
+}
+
+
+
+/// The last two digits of every valid channel name. 00, 25, 50 and 75 are the 25 kHz channels,
+/// the rest are 8.33 kHz channel names.
+pub const VALID_CHANNELS: [u16; 16] = [0, 5, 10, 15, 25, 30, 35, 40, 50, 55, 60, 65, 75, 80, 85, 90];
 
+/// The MHz range of the VHF aeronautical mobile band. The band ends at 136.975 (25 kHz) / 136.990 (8.33 kHz).
+pub const BAND_LEFT: std::ops::RangeInclusive<u16> = 118..=136;
+
+const CHANNELS_PER_MHZ: u16 = 10 * VALID_CHANNELS.len() as u16;
+const CHANNEL_COUNT: u16 = (*BAND_LEFT.end() - *BAND_LEFT.start() + 1) * CHANNELS_PER_MHZ;
+
+/// Offsets of the three 8.33 kHz carriers inside a 25 kHz block, rounded to the nearest Hz.
+const CARRIER_OFFSETS_HZ: [u32; 3] = [0, 8_333, 16_667];
+
+/// The channel spacing a frequency is assigned with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ChannelSpacing {
+    Khz25,
+    Khz8_33,
+}
+
+/// Position of a kHz part in the channel list of one MHz. Shared by every band with COM channel semantics.
+const fn channel_position(right: u16) -> Result<u16, RadioFrequencyError> {
+    if right > 999 {
+        return Err(RadioFrequencyError::RightOutOfRange);
+    }
+    
+    //First digit doesn't matter. Get the last two.
+    let last_two = right % 100;
+    let mut position = 0;
+    while VALID_CHANNELS[position] != last_two {
+        position += 1;
+        if position == VALID_CHANNELS.len() {
+            return Err(RadioFrequencyError::NotAChannel);
+        }
+    }
+    Ok(right / 100 * VALID_CHANNELS.len() as u16 + position as u16)
+}
+
+/// Carrier offset in Hz from the start of the MHz for a valid kHz part.
+const fn carrier_offset_hz(right: u16) -> u32 {
+    let block = right - right % 25;
+    let offset = if right.is_multiple_of(25) {
+        0
+    } else {
+        CARRIER_OFFSETS_HZ[(right % 25 / 5 - 1) as usize]
+    };
+    block as u32 * 1000 + offset
+}
+
+/// Splits a carrier frequency in Hz into the MHz and kHz parts of its channel name. 8.33 kHz carriers are
+/// shifted up by 1 Hz before choosing the 25 kHz block, so a carrier rounded 1 Hz low stays in its block.
+fn split_carrier_hz(hz: u32, spacing: ChannelSpacing) -> Result<(u16, u16), RadioFrequencyError> {
+    let hz = match spacing {
+        ChannelSpacing::Khz25 => hz,
+        ChannelSpacing::Khz8_33 => hz.saturating_add(1),
+    };
+    let left = u16::try_from(hz / 1_000_000).map_err(|_| RadioFrequencyError::LeftOutOfBand)?;
+    let block = (hz % 1_000_000 / 25_000 * 25) as u16;
+    let offset = hz % 25_000;
+    match spacing {
+        ChannelSpacing::Khz25 if offset == 0 => Ok((left, block)),
+        ChannelSpacing::Khz25 => Err(RadioFrequencyError::NotAChannel),
+        ChannelSpacing::Khz8_33 => {
+            let index = CARRIER_OFFSETS_HZ
+                .iter()
+                .position(|&o| (o + 1).abs_diff(offset) <= 1)
+                .ok_or(RadioFrequencyError::NotAChannel)?;
+            Ok((left, block + 5 * (index as u16 + 1)))
+        }
+    }
+}
 
 impl RadioFrequency {
-    pub fn new(left: u16, right: u16) -> Result<RadioFrequency, RadioFrequencyError> {
+    pub const fn new(left: u16, right: u16) -> Result<RadioFrequency, RadioFrequencyError> {
         
         // Validate left
-        if !(118..=137).contains(&left) {
-            return Err(RadioFrequencyError::InvalidFrequency);
+        if left < *BAND_LEFT.start() || left > *BAND_LEFT.end() {
+            return Err(RadioFrequencyError::LeftOutOfBand);
         }
         
         // Validate right
+        let position = match channel_position(right) {
+            Ok(position) => position,
+            Err(error) => return Err(error),
+        };
         
-        //First digit doesn't matter. Get the last two.
-        let last_two = right % 100;
-        const VALID_CHANNELS: [u16; 16] = [00, 05, 10, 15, 25, 30, 35, 40, 50, 55, 60, 65, 75, 80, 85, 90];
-        if !&VALID_CHANNELS[..].contains(&last_two) {
-            return Err(RadioFrequencyError::InvalidFrequency);
-        }
-        
-        Ok(RadioFrequency {
-            left,
-            right,
-            is_25_khz_spaced: [00, 25, 50, 75].contains(&last_two),
-        })
+        let ordinal = (left - *BAND_LEFT.start()) * CHANNELS_PER_MHZ + position;
+        Ok(RadioFrequency::from_ordinal(ordinal))
         
     }
-    pub fn is_8_33_khz_spaced(&self) -> bool {
-        !self.is_25_khz_spaced
+    /// Parses a frequency in const context, with the same rules as the `FromStr` impl.
+    /// See also the [`freq!`] macro.
+    pub const fn parse(s: &str) -> Result<RadioFrequency, RadioFrequencyError> {
+        match decimal::parse_decimal(s, 3, true) {
+            Ok((left, right)) if left <= u16::MAX as u32 => RadioFrequency::new(left as u16, right),
+            Ok(_) => Err(RadioFrequencyError::LeftOutOfBand),
+            Err(error) => Err(RadioFrequencyError::from_decimal(error)),
+        }
     }
-    pub fn is_25_khz_spaced(&self) -> bool {
-        self.is_25_khz_spaced
+
+    pub const fn is_8_33_khz_spaced(&self) -> bool {
+        !self.is_25_khz_spaced()
+    }
+    pub const fn is_25_khz_spaced(&self) -> bool {
+        // Every fourth entry in VALID_CHANNELS is a 25 kHz channel.
+        self.ordinal().is_multiple_of(4)
     }
     
-    pub fn frequency(&self) -> (u16, u16) {
-        (self.left, self.right)
+    pub const fn spacing(&self) -> ChannelSpacing {
+        if self.is_25_khz_spaced() {
+            ChannelSpacing::Khz25
+        } else {
+            ChannelSpacing::Khz8_33
+        }
+    }
+
+    /// The actual carrier frequency in Hz, as opposed to the channel name.
+    /// 118.005 is the 118.000 MHz carrier and 118.010 is 118.00833 MHz, rounded to 118_008_333 Hz.
+    pub const fn carrier_hz(&self) -> u32 {
+        self.left() as u32 * 1_000_000 + carrier_offset_hz(self.right())
+    }
+
+    /// Finds the channel name for a carrier frequency in Hz. 8.33 kHz carriers may be off by 1 Hz either
+    /// way to allow for rounding.
+    pub fn from_carrier_hz(hz: u32, spacing: ChannelSpacing) -> Result<RadioFrequency, RadioFrequencyError> {
+        let (left, right) = split_carrier_hz(hz, spacing)?;
+        RadioFrequency::new(left, right)
+    }
+
+    /// The channel name in kHz, e.g. 118005 for 118.005. This is lossless, unlike [`RadioFrequency::carrier_hz`].
+    pub const fn khz(&self) -> u32 {
+        self.left() as u32 * 1000 + self.right() as u32
+    }
+
+    /// The channel name in Hz, e.g. 118_005_000 for 118.005.
+    pub const fn hz(&self) -> u32 {
+        self.khz() * 1000
+    }
+
+    /// The channel name in MHz. Exact up to the precision of `f64`.
+    pub fn mhz(&self) -> f64 {
+        self.khz() as f64 / 1000.0
+    }
+
+    pub const fn from_khz(khz: u32) -> Result<RadioFrequency, RadioFrequencyError> {
+        if khz / 1000 > u16::MAX as u32 {
+            return Err(RadioFrequencyError::LeftOutOfBand);
+        }
+        RadioFrequency::new((khz / 1000) as u16, (khz % 1000) as u16)
+    }
+
+    /// Rejects any value that is not a whole number of kHz.
+    pub const fn from_hz(hz: u32) -> Result<RadioFrequency, RadioFrequencyError> {
+        if !hz.is_multiple_of(1000) {
+            return Err(RadioFrequencyError::NotWholeKhz);
+        }
+        RadioFrequency::from_khz(hz / 1000)
+    }
+
+    /// Like [`RadioFrequency::from_khz`], but also takes an 8.33 kHz carrier cut to the kHz, rounded or
+    /// truncated, as some simulators and network clients send. 118008 gives 118.010, 118016 or 118017 give 118.015.
+    pub(crate) fn from_khz_or_carrier_khz(khz: u32) -> Result<RadioFrequency, RadioFrequencyError> {
+        let name = match RadioFrequency::from_khz(khz) {
+            Err(RadioFrequencyError::NotAChannel) => match khz % 25 {
+                // 8.333 and 16.667 kHz into a 25 kHz block are the 8.33 kHz channels named +10 and +15.
+                8 => khz + 2,
+                16 => khz - 1,
+                17 => khz - 2,
+                _ => khz,
+            },
+            _ => khz,
+        };
+        RadioFrequency::from_khz(name)
+    }
+
+    /// Rounds to the nearest kHz, but rejects the value if that moves it by more than 1 Hz.
+    pub fn from_mhz(mhz: f64) -> Result<RadioFrequency, RadioFrequencyError> {
+        let khz = mhz * 1000.0;
+        let rounded = khz.round();
+        if !khz.is_finite() || (khz - rounded).abs() > 0.001 || !(0.0..=u32::MAX as f64).contains(&rounded) {
+            return Err(RadioFrequencyError::InexactMhz);
+        }
+        RadioFrequency::from_khz(rounded as u32)
+    }
+
+    const fn ordinal(&self) -> u16 {
+        self.index.get()
+    }
+
+    const fn from_ordinal(ordinal: u16) -> RadioFrequency {
+        RadioFrequency {
+            index: ChannelIndex(ordinal),
+        }
+    }
+
+    /// Position of this channel in the ordered list of every channel name in the band.
+    pub const fn index(&self) -> ChannelIndex {
+        self.index
+    }
+
+    pub const fn frequency(&self) -> (u16, u16) {
+        (self.left(), self.right())
     }
     
-    pub fn left(&self) -> u16 {
-        self.left
+    pub const fn left(&self) -> u16 {
+        *BAND_LEFT.start() + self.ordinal() / CHANNELS_PER_MHZ
     }
     
-    pub fn right(&self) -> u16 {
-        self.right
+    pub const fn right(&self) -> u16 {
+        let within_mhz = self.ordinal() % CHANNELS_PER_MHZ;
+        within_mhz / VALID_CHANNELS.len() as u16 * 100 + VALID_CHANNELS[within_mhz as usize % VALID_CHANNELS.len()]
     }
 }
 
 impl std::fmt::Display for RadioFrequency {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:03}.{:03}", self.left, self.right)
+        write!(f, "{:03}.{:03}", self.left(), self.right())
+    }
+}
+
+impl From<RadioFrequency> for f64 {
+    fn from(value: RadioFrequency) -> Self {
+        value.mhz()
+    }
+}
+
+impl TryFrom<f64> for RadioFrequency {
+    type Error = RadioFrequencyError;
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        RadioFrequency::from_mhz(value)
     }
 }
 
@@ -64,21 +325,75 @@ impl std::fmt::Display for RadioFrequency {
 impl FromStr for RadioFrequency {
     type Err = RadioFrequencyError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut parts = s.split('.');
-        let left = parts.next().ok_or(RadioFrequencyError::NotEnoughParts)?.parse::<u16>()?;
-        let right = parts.next().ok_or(RadioFrequencyError::NotEnoughParts)?.parse::<u16>()?;
-        RadioFrequency::new(left, right)
+        RadioFrequency::parse(s)
     }
 }
 
+/// Builds a [`RadioFrequency`] from a string literal at compile time. Invalid channels fail to compile.
+///
+/// ```
+/// use aviation_radio::{freq, RadioFrequency};
+/// const GUARD: RadioFrequency = freq!("121.500");
+/// assert_eq!(GUARD.frequency(), (121, 500));
+/// ```
+///
+/// ```compile_fail
+/// let invalid = aviation_radio::freq!("121.520");
+/// ```
+#[macro_export]
+macro_rules! freq {
+    ($frequency:literal) => {{
+        const FREQUENCY: $crate::RadioFrequency = match $crate::RadioFrequency::parse($frequency) {
+            Ok(frequency) => frequency,
+            Err(_) => panic!(concat!("invalid radio frequency: ", $frequency)),
+        };
+        FREQUENCY
+    }};
+}
+
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum RadioFrequencyError {
     InvalidFrequency,
+    LeftOutOfBand,
+    RightOutOfRange,
+    NotAChannel,
+    NotWholeKhz,
+    InexactMhz,
+    IndexOutOfRange,
+    PolicyViolation(SpacingViolation),
     NotEnoughParts,
+    TooManyParts,
+    MissingMhz,
+    MissingDecimals,
+    TooManyDecimals,
+    UnexpectedSign,
+    UnexpectedWhitespace,
+    InvalidCharacter,
     ParseError(ParseIntError),
 }
 
+impl RadioFrequencyError {
+    const fn from_decimal(error: DecimalError) -> Self {
+        match error {
+            DecimalError::NotEnoughParts => Self::NotEnoughParts,
+            DecimalError::TooManyParts => Self::TooManyParts,
+            DecimalError::MissingWhole => Self::MissingMhz,
+            DecimalError::MissingDecimals => Self::MissingDecimals,
+            DecimalError::TooManyDecimals => Self::TooManyDecimals,
+            DecimalError::UnexpectedSign => Self::UnexpectedSign,
+            DecimalError::UnexpectedWhitespace => Self::UnexpectedWhitespace,
+            DecimalError::InvalidCharacter => Self::InvalidCharacter,
+        }
+    }
+}
+
+impl From<SpacingViolation> for RadioFrequencyError {
+    fn from(value: SpacingViolation) -> Self {
+        Self::PolicyViolation(value)
+    }
+}
+
 impl From<ParseIntError> for RadioFrequencyError {
     fn from(value: ParseIntError) -> Self {
         Self::ParseError(value)
@@ -90,6 +405,20 @@ impl std::fmt::Display for RadioFrequencyError {
         write!(f, "{}", match self {
             Self::NotEnoughParts => "Not enough parts",
             Self::InvalidFrequency => "Invalid frequency",
+            Self::LeftOutOfBand => "MHz part outside the band",
+            Self::RightOutOfRange => "kHz part outside 0-999",
+            Self::NotAChannel => "Not a valid channel",
+            Self::NotWholeKhz => "Not a whole number of kHz",
+            Self::InexactMhz => "MHz value is not within 1 Hz of a whole kHz",
+            Self::IndexOutOfRange => "Channel index out of range",
+            Self::PolicyViolation(_) => "Not permitted by the spacing policy",
+            Self::TooManyParts => "Too many parts",
+            Self::MissingMhz => "Missing MHz",
+            Self::MissingDecimals => "Missing decimals",
+            Self::TooManyDecimals => "More than three decimals",
+            Self::UnexpectedSign => "Unexpected sign",
+            Self::UnexpectedWhitespace => "Unexpected whitespace",
+            Self::InvalidCharacter => "Invalid character",
             Self::ParseError(_) => "Int parse error",
         })
     }
@@ -99,15 +428,15 @@ impl std::error::Error for RadioFrequencyError {}
 
 #[cfg(test)]
 mod tests {
-    use crate::RadioFrequency;
+    use crate::{ChannelSpacing, RadioFrequency, RadioFrequencyError, RawRadioFrequency};
 
     #[test]
     fn validate() {
         let valid = RadioFrequency::new(120, 905).unwrap();
         let invalid_a = RadioFrequency::new(110, 300);
         let invalid_b = RadioFrequency::new(118, 3);
-        let invalid_c = RadioFrequency::new(138, 005);
-        let invalid_d = RadioFrequency::new(121, 012);
+        let invalid_c = RadioFrequency::new(138, 5);
+        let invalid_d = RadioFrequency::new(121, 12);
 
         assert!(invalid_a.is_err());
         assert!(invalid_b.is_err());
@@ -119,4 +448,111 @@ mod tests {
 
         assert!(valid.is_8_33_khz_spaced())
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn parse() {
+        let expected = RadioFrequency::new(118, 500).unwrap();
+        assert_eq!("118.5".parse::<RadioFrequency>().unwrap(), expected);
+        assert_eq!("118.50".parse::<RadioFrequency>().unwrap(), expected);
+        assert_eq!("118.500".parse::<RadioFrequency>().unwrap(), expected);
+        assert_eq!("121.05".parse::<RadioFrequency>().unwrap(), RadioFrequency::new(121, 50).unwrap());
+        assert_eq!("132.005".parse::<RadioFrequency>().unwrap(), RadioFrequency::new(132, 5).unwrap());
+
+        assert_eq!("118.5000".parse::<RadioFrequency>(), Err(RadioFrequencyError::TooManyDecimals));
+        assert_eq!("118.000.5".parse::<RadioFrequency>(), Err(RadioFrequencyError::TooManyParts));
+        assert_eq!("118.".parse::<RadioFrequency>(), Err(RadioFrequencyError::MissingDecimals));
+        assert_eq!("118".parse::<RadioFrequency>(), Err(RadioFrequencyError::NotEnoughParts));
+        assert_eq!("+118.5".parse::<RadioFrequency>(), Err(RadioFrequencyError::UnexpectedSign));
+        assert_eq!("-118.5".parse::<RadioFrequency>(), Err(RadioFrequencyError::UnexpectedSign));
+        assert_eq!(" 118.5 ".parse::<RadioFrequency>(), Err(RadioFrequencyError::UnexpectedWhitespace));
+        assert_eq!("118.5x".parse::<RadioFrequency>(), Err(RadioFrequencyError::InvalidCharacter));
+        assert_eq!("118.5é".parse::<RadioFrequency>(), Err(RadioFrequencyError::InvalidCharacter));
+        assert_eq!("118.5\u{a0}".parse::<RadioFrequency>(), Err(RadioFrequencyError::UnexpectedWhitespace));
+        assert_eq!("118.5\u{2009}".parse::<RadioFrequency>(), Err(RadioFrequencyError::UnexpectedWhitespace));
+        assert_eq!(".5".parse::<RadioFrequency>(), Err(RadioFrequencyError::MissingMhz));
+        assert_eq!("99999999.5".parse::<RadioFrequency>(), Err(RadioFrequencyError::LeftOutOfBand));
+    }
+
+    #[test]
+    fn validate_band_edges() {
+        assert!(RadioFrequency::new(118, 0).is_ok());
+        assert!(RadioFrequency::new(136, 975).is_ok());
+        assert!(RadioFrequency::new(136, 990).is_ok());
+        assert_eq!(RadioFrequency::new(137, 0), Err(RadioFrequencyError::LeftOutOfBand));
+        assert_eq!(RadioFrequency::new(117, 975), Err(RadioFrequencyError::LeftOutOfBand));
+        assert_eq!(RadioFrequency::new(121, 1000), Err(RadioFrequencyError::RightOutOfRange));
+        assert_eq!(RadioFrequency::new(121, 20), Err(RadioFrequencyError::NotAChannel));
+    }
+
+    #[test]
+    fn carrier() {
+        assert_eq!(RadioFrequency::new(118, 0).unwrap().carrier_hz(), 118_000_000);
+        assert_eq!(RadioFrequency::new(118, 5).unwrap().carrier_hz(), 118_000_000);
+        assert_eq!(RadioFrequency::new(118, 10).unwrap().carrier_hz(), 118_008_333);
+        assert_eq!(RadioFrequency::new(118, 15).unwrap().carrier_hz(), 118_016_667);
+        assert_eq!(RadioFrequency::new(136, 990).unwrap().carrier_hz(), 136_991_667);
+
+        assert_eq!(RadioFrequency::from_carrier_hz(118_000_000, ChannelSpacing::Khz25).unwrap(), RadioFrequency::new(118, 0).unwrap());
+        assert_eq!(RadioFrequency::from_carrier_hz(118_000_000, ChannelSpacing::Khz8_33).unwrap(), RadioFrequency::new(118, 5).unwrap());
+        assert_eq!(RadioFrequency::from_carrier_hz(132_841_666, ChannelSpacing::Khz8_33).unwrap(), RadioFrequency::new(132, 840).unwrap());
+        assert_eq!(RadioFrequency::from_carrier_hz(118_025_001, ChannelSpacing::Khz8_33).unwrap(), RadioFrequency::new(118, 30).unwrap());
+        assert_eq!(RadioFrequency::from_carrier_hz(118_024_999, ChannelSpacing::Khz8_33).unwrap(), RadioFrequency::new(118, 30).unwrap());
+        assert_eq!(RadioFrequency::from_carrier_hz(118_999_999, ChannelSpacing::Khz8_33).unwrap(), RadioFrequency::new(119, 5).unwrap());
+        assert_eq!(RadioFrequency::from_carrier_hz(118_016_668, ChannelSpacing::Khz8_33).unwrap(), RadioFrequency::new(118, 15).unwrap());
+        assert_eq!(RadioFrequency::from_carrier_hz(118_016_669, ChannelSpacing::Khz8_33), Err(RadioFrequencyError::NotAChannel));
+        assert_eq!(RadioFrequency::from_carrier_hz(118_024_998, ChannelSpacing::Khz8_33), Err(RadioFrequencyError::NotAChannel));
+        assert_eq!(RadioFrequency::from_carrier_hz(118_008_333, ChannelSpacing::Khz25), Err(RadioFrequencyError::NotAChannel));
+        assert_eq!(RadioFrequency::from_carrier_hz(118_004_000, ChannelSpacing::Khz8_33), Err(RadioFrequencyError::NotAChannel));
+
+        for channel in [(121, 500), (121, 505), (121, 510), (121, 515), (136, 990)] {
+            let frequency = RadioFrequency::new(channel.0, channel.1).unwrap();
+            assert_eq!(RadioFrequency::from_carrier_hz(frequency.carrier_hz(), frequency.spacing()).unwrap(), frequency);
+        }
+    }
+
+    #[test]
+    fn integer_conversions() {
+        let frequency = RadioFrequency::new(118, 5).unwrap();
+        assert_eq!(frequency.khz(), 118_005);
+        assert_eq!(frequency.hz(), 118_005_000);
+        assert_eq!(RadioFrequency::from_khz(118_005).unwrap(), frequency);
+        assert_eq!(RadioFrequency::from_hz(118_005_000).unwrap(), frequency);
+        assert_eq!(RadioFrequency::from_khz(118_020), Err(RadioFrequencyError::NotAChannel));
+        assert_eq!(RadioFrequency::from_khz(137_000), Err(RadioFrequencyError::LeftOutOfBand));
+        assert_eq!(RadioFrequency::from_hz(118_005_001), Err(RadioFrequencyError::NotWholeKhz));
+    }
+
+    #[test]
+    fn mhz_conversions() {
+        let frequency = RadioFrequency::new(121, 505).unwrap();
+        assert_eq!(f64::from(frequency), 121.505);
+        assert_eq!(RadioFrequency::try_from(121.505).unwrap(), frequency);
+        assert_eq!(RadioFrequency::try_from(121.505_000_4).unwrap(), frequency);
+        assert_eq!(RadioFrequency::try_from(121.5051), Err(RadioFrequencyError::InexactMhz));
+        assert_eq!(RadioFrequency::try_from(f64::NAN), Err(RadioFrequencyError::InexactMhz));
+        assert_eq!(RadioFrequency::try_from(-121.5), Err(RadioFrequencyError::InexactMhz));
+    }
+
+    #[test]
+    fn representation() {
+        let frequency = RadioFrequency::new(118, 5).unwrap();
+        assert_eq!(std::mem::size_of::<RadioFrequency>(), 2);
+        assert_eq!(format!("{frequency:?}"), "RadioFrequency { left: 118, right: 5, is_25_khz_spaced: false }");
+
+        let raw = RawRadioFrequency::from(frequency);
+        assert_eq!((raw.left, raw.right, raw.is_25_khz_spaced), (118, 5, false));
+        assert_eq!(RadioFrequency::try_from(raw).unwrap(), frequency);
+        assert!(RadioFrequency::try_from(RawRadioFrequency { left: 118, right: 5, is_25_khz_spaced: true }).is_err());
+        assert!(RadioFrequency::try_from(RawRadioFrequency { left: 137, right: 0, is_25_khz_spaced: true }).is_err());
+    }
+
+    #[test]
+    fn const_construction() {
+        const GUARD: RadioFrequency = crate::freq!("121.5");
+        const SECTOR: Result<RadioFrequency, RadioFrequencyError> = RadioFrequency::new(132, 605);
+        const TABLE: [RadioFrequency; 2] = [crate::freq!("118.005"), crate::freq!("136.990")];
+        assert_eq!(GUARD, RadioFrequency::new(121, 500).unwrap());
+        assert!(SECTOR.is_ok());
+        assert_eq!(TABLE[1].frequency(), (136, 990));
+    }
+}
@@ -0,0 +1,230 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{AviationFrequency, Band};
+
+/// Offset from the ship to the coast station frequency on duplex channels.
+const DUPLEX_OFFSET_HZ: u32 = 4_600_000;
+
+/// Turns a duplex channel into a simplex one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum MarineSuffix {
+    /// Simplex on the ship frequency, written 10xx in ITU-R M.1084.
+    A,
+    /// Simplex on the coast station frequency, written 20xx in ITU-R M.1084.
+    B,
+}
+
+/// An ITU Appendix 18 marine VHF channel, 1 to 28 or 60 to 88, optionally with an A or B suffix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "RawMarineChannel")]
+pub struct MarineChannel {
+    number: u8,
+    suffix: Option<MarineSuffix>,
+}
+
+#[derive(Deserialize)]
+struct RawMarineChannel {
+    number: u8,
+    suffix: Option<MarineSuffix>,
+}
+
+impl TryFrom<RawMarineChannel> for MarineChannel {
+    type Error = MarineChannelError;
+    fn try_from(value: RawMarineChannel) -> Result<Self, Self::Error> {
+        MarineChannel::new(value.number, value.suffix)
+    }
+}
+
+impl MarineChannel {
+    /// Channel 16, distress, safety and calling.
+    pub const DISTRESS: MarineChannel = MarineChannel { number: 16, suffix: None };
+
+    /// Suffixes are only accepted on duplex channels.
+    pub const fn new(number: u8, suffix: Option<MarineSuffix>) -> Result<MarineChannel, MarineChannelError> {
+        if !matches!(number, 1..=28 | 60..=88) {
+            return Err(MarineChannelError::InvalidNumber);
+        }
+        if suffix.is_some() && !is_duplex(number) {
+            return Err(MarineChannelError::SuffixOnSimplex);
+        }
+        Ok(MarineChannel { number, suffix })
+    }
+
+    pub const fn number(&self) -> u8 {
+        self.number
+    }
+
+    pub const fn suffix(&self) -> Option<MarineSuffix> {
+        self.suffix
+    }
+
+    /// Whether ships and coast stations transmit on different frequencies.
+    pub const fn is_duplex(&self) -> bool {
+        self.suffix.is_none() && is_duplex(self.number)
+    }
+
+    const fn lower_hz(&self) -> u32 {
+        match self.number {
+            1..=28 => 156_000_000 + self.number as u32 * 50_000,
+            60..=88 => 156_025_000 + (self.number as u32 - 60) * 50_000,
+            _ => panic!("invalid marine channel number"),
+        }
+    }
+
+    pub const fn ship_transmit_hz(&self) -> u32 {
+        match self.suffix {
+            Some(MarineSuffix::B) => self.lower_hz() + DUPLEX_OFFSET_HZ,
+            _ => self.lower_hz(),
+        }
+    }
+
+    pub const fn coast_transmit_hz(&self) -> u32 {
+        match self.suffix {
+            Some(MarineSuffix::A) => self.lower_hz(),
+            Some(MarineSuffix::B) => self.lower_hz() + DUPLEX_OFFSET_HZ,
+            None if is_duplex(self.number) => self.lower_hz() + DUPLEX_OFFSET_HZ,
+            None => self.lower_hz(),
+        }
+    }
+
+    /// Whether a radio that tunes `coverage_hz` can both transmit and listen on this channel as a ship would.
+    /// Aviation COM radios stop at 137 MHz, so pass the coverage of the aircraft's VHF-FM set, if it has one.
+    pub fn reachable_by(&self, coverage_hz: &std::ops::RangeInclusive<u32>) -> bool {
+        coverage_hz.contains(&self.ship_transmit_hz()) && coverage_hz.contains(&self.coast_transmit_hz())
+    }
+}
+
+const fn is_duplex(number: u8) -> bool {
+    matches!(number, 1..=5 | 7 | 18..=28 | 60..=66 | 78..=88)
+}
+
+/// Reports the ship transmit frequency.
+impl AviationFrequency for MarineChannel {
+    fn hz(&self) -> u32 {
+        self.ship_transmit_hz()
+    }
+
+    fn band(&self) -> Band {
+        Band::Marine
+    }
+
+    fn spacing_hz(&self) -> u32 {
+        25_000
+    }
+}
+
+impl std::fmt::Display for MarineChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.suffix {
+            Some(suffix) => write!(f, "{}{:?}", self.number, suffix),
+            None => write!(f, "{}", self.number),
+        }
+    }
+}
+
+/// Accepts "16", "18A", "87B" and the ITU-R M.1084 forms "1018" and "2087".
+impl FromStr for MarineChannel {
+    type Err = MarineChannelError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (digits, suffix) = if let Some(digits) = s.strip_suffix(['A', 'a']) {
+            (digits, Some(MarineSuffix::A))
+        } else if let Some(digits) = s.strip_suffix(['B', 'b']) {
+            (digits, Some(MarineSuffix::B))
+        } else {
+            (s, None)
+        };
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(MarineChannelError::InvalidNumber);
+        }
+        let (digits, suffix) = match (digits.len(), suffix) {
+            (4, None) if digits.starts_with("10") => (&digits[2..], Some(MarineSuffix::A)),
+            (4, None) if digits.starts_with("20") => (&digits[2..], Some(MarineSuffix::B)),
+            (1 | 2, _) => (digits, suffix),
+            _ => return Err(MarineChannelError::InvalidNumber),
+        };
+        let number = digits.parse::<u8>().map_err(|_| MarineChannelError::InvalidNumber)?;
+        MarineChannel::new(number, suffix)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MarineChannelError {
+    InvalidNumber,
+    SuffixOnSimplex,
+}
+
+impl std::fmt::Display for MarineChannelError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            Self::InvalidNumber => "Not a marine VHF channel",
+            Self::SuffixOnSimplex => "A/B suffix on a simplex channel",
+        })
+    }
+}
+
+impl std::error::Error for MarineChannelError {}
+
+#[cfg(test)]
+mod tests {
+    use super::RawMarineChannel;
+    use crate::{AviationFrequency, Band, MarineChannel, MarineChannelError, MarineSuffix};
+
+    #[test]
+    fn frequencies() {
+        let cases = [
+            ("16", 156_800_000, 156_800_000),
+            ("6", 156_300_000, 156_300_000),
+            ("67", 156_375_000, 156_375_000),
+            ("1", 156_050_000, 160_650_000),
+            ("88", 157_425_000, 162_025_000),
+            ("18A", 156_900_000, 156_900_000),
+            ("87B", 161_975_000, 161_975_000),
+            ("1018", 156_900_000, 156_900_000),
+            ("2088", 162_025_000, 162_025_000),
+        ];
+        for (s, ship, coast) in cases {
+            let channel: MarineChannel = s.parse().unwrap();
+            assert_eq!((channel.ship_transmit_hz(), channel.coast_transmit_hz()), (ship, coast), "{s}");
+        }
+        assert_eq!("16".parse::<MarineChannel>().unwrap(), MarineChannel::DISTRESS);
+        assert!("22".parse::<MarineChannel>().unwrap().is_duplex());
+        assert!(!"22A".parse::<MarineChannel>().unwrap().is_duplex());
+        assert_eq!("2088".parse::<MarineChannel>().unwrap().to_string(), "88B");
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!("29".parse::<MarineChannel>(), Err(MarineChannelError::InvalidNumber));
+        assert_eq!("0".parse::<MarineChannel>(), Err(MarineChannelError::InvalidNumber));
+        assert_eq!("3016".parse::<MarineChannel>(), Err(MarineChannelError::InvalidNumber));
+        assert_eq!("16A".parse::<MarineChannel>(), Err(MarineChannelError::SuffixOnSimplex));
+        assert_eq!(MarineChannel::new(16, Some(MarineSuffix::B)), Err(MarineChannelError::SuffixOnSimplex));
+    }
+
+    #[test]
+    fn raw() {
+        assert_eq!(MarineChannel::try_from(RawMarineChannel { number: 16, suffix: None }), Ok(MarineChannel::DISTRESS));
+        for number in [0, 40, 200] {
+            assert_eq!(MarineChannel::try_from(RawMarineChannel { number, suffix: None }), Err(MarineChannelError::InvalidNumber));
+        }
+        assert_eq!(
+            MarineChannel::try_from(RawMarineChannel { number: 16, suffix: Some(MarineSuffix::A) }),
+            Err(MarineChannelError::SuffixOnSimplex)
+        );
+    }
+
+    #[test]
+    fn reachability() {
+        // A VHF-FM set whose coverage ends at 160 MHz hears ships but not the upper coast station frequencies.
+        let fm_to_160 = 138_000_000..=160_000_000;
+        for (channel, reachable) in [("16", true), ("6", true), ("67", true), ("18A", true), ("22", false), ("87B", false)] {
+            assert_eq!(channel.parse::<MarineChannel>().unwrap().reachable_by(&fm_to_160), reachable, "{channel}");
+        }
+        assert!("22".parse::<MarineChannel>().unwrap().reachable_by(&(138_000_000..=174_000_000)));
+        assert!(!MarineChannel::DISTRESS.reachable_by(&Band::Com.hz_range()));
+        assert!(MarineChannel::DISTRESS.reachable_by(&Band::Marine.hz_range()));
+        assert_eq!(MarineChannel::DISTRESS.band(), Band::Marine);
+    }
+}
@@ -0,0 +1,129 @@
+//! SimConnect COM frequency encodings used by MSFS and FSX.
+//!
+//! BCD16 holds the four digits after the leading "1" with two decimals, so 0x2150 is 121.50. It cannot carry
+//! the third decimal, so decoding needs a [`ChannelFilter`] to pick between e.g. 121.500 and 121.505.
+//! BCD32 holds the MHz with four decimals, so 0x01180050 is 118.0050, and carries every channel name.
+//! For Hz values use [`RadioFrequency::from_hz`] and [`RadioFrequency::hz`]; like BCD32 they hold the channel
+//! name, not the 8.33 kHz carrier.
+
+use crate::{channels, ChannelFilter, RadioFrequency, RadioFrequencyError};
+
+fn encode_bcd(mut value: u32) -> u32 {
+    let mut bcd = 0;
+    let mut shift = 0;
+    while value > 0 {
+        bcd |= (value % 10) << shift;
+        value /= 10;
+        shift += 4;
+    }
+    bcd
+}
+
+fn decode_bcd(mut bcd: u32) -> Result<u32, MsfsError> {
+    let mut value = 0;
+    let mut scale = 1;
+    while bcd > 0 {
+        let digit = bcd & 0xF;
+        if digit > 9 {
+            return Err(MsfsError::InvalidBcd);
+        }
+        value += digit * scale;
+        bcd >>= 4;
+        scale *= 10;
+    }
+    Ok(value)
+}
+
+/// Drops the leading "1" and the third decimal, so 121.505 becomes 0x2150.
+pub fn to_bcd16(frequency: RadioFrequency) -> u16 {
+    encode_bcd(frequency.khz() / 10 % 10_000) as u16
+}
+
+/// Every channel name that encodes to `bcd`, in ascending order. Empty if none does.
+pub fn bcd16_candidates(bcd: u16) -> Result<Vec<RadioFrequency>, MsfsError> {
+    Ok(channels::truncated_to_10_khz(10_000 + decode_bcd(bcd as u32)?, ChannelFilter::All))
+}
+
+/// Decodes BCD16, keeping only candidates matching `filter`. With [`ChannelFilter::Khz25`] the result is
+/// always unique; with the others, a value such as 0x2150 (121.500 or 121.505) is reported as ambiguous.
+pub fn from_bcd16(bcd: u16, filter: ChannelFilter) -> Result<RadioFrequency, MsfsError> {
+    let candidates = channels::truncated_to_10_khz(10_000 + decode_bcd(bcd as u32)?, filter);
+    match candidates[..] {
+        [] => Err(MsfsError::NoChannel),
+        [frequency] => Ok(frequency),
+        _ => Err(MsfsError::Ambiguous(candidates)),
+    }
+}
+
+pub fn to_bcd32(frequency: RadioFrequency) -> u32 {
+    encode_bcd(frequency.khz() * 10)
+}
+
+/// Rejects values whose fourth decimal is not zero, as no channel name has one.
+pub fn from_bcd32(bcd: u32) -> Result<RadioFrequency, MsfsError> {
+    let value = decode_bcd(bcd)?;
+    if value % 10 != 0 {
+        return Err(MsfsError::Frequency(RadioFrequencyError::NotWholeKhz));
+    }
+    Ok(RadioFrequency::from_khz(value / 10)?)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MsfsError {
+    InvalidBcd,
+    NoChannel,
+    Ambiguous(Vec<RadioFrequency>),
+    Frequency(RadioFrequencyError),
+}
+
+impl From<RadioFrequencyError> for MsfsError {
+    fn from(value: RadioFrequencyError) -> Self {
+        Self::Frequency(value)
+    }
+}
+
+impl std::fmt::Display for MsfsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidBcd => write!(f, "Not a valid BCD value"),
+            Self::NoChannel => write!(f, "No channel matches"),
+            Self::Ambiguous(candidates) => write!(f, "Ambiguous, {} channels match", candidates.len()),
+            Self::Frequency(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for MsfsError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{bcd16_candidates, from_bcd16, from_bcd32, to_bcd16, to_bcd32, MsfsError};
+    use crate::{freq, ChannelFilter, RadioFrequencyError};
+
+    #[test]
+    fn bcd16() {
+        assert_eq!(to_bcd16(freq!("121.500")), 0x2150);
+        assert_eq!(to_bcd16(freq!("121.505")), 0x2150);
+        assert_eq!(to_bcd16(freq!("118.025")), 0x1802);
+        assert_eq!(from_bcd16(0x2150, ChannelFilter::Khz25), Ok(freq!("121.500")));
+        assert_eq!(from_bcd16(0x1802, ChannelFilter::Khz25), Ok(freq!("118.025")));
+        assert_eq!(from_bcd16(0x2150, ChannelFilter::Khz8_33), Ok(freq!("121.505")));
+        assert_eq!(from_bcd16(0x2150, ChannelFilter::All), Err(MsfsError::Ambiguous(vec![freq!("121.500"), freq!("121.505")])));
+        assert_eq!(from_bcd16(0x2151, ChannelFilter::Khz25), Err(MsfsError::NoChannel));
+        assert_eq!(from_bcd16(0x215A, ChannelFilter::Khz25), Err(MsfsError::InvalidBcd));
+        assert_eq!(bcd16_candidates(0x3801), Ok(vec![]));
+
+        for frequency in crate::RadioFrequency::channels(ChannelFilter::Khz25) {
+            assert_eq!(from_bcd16(to_bcd16(frequency), ChannelFilter::Khz25), Ok(frequency));
+        }
+    }
+
+    #[test]
+    fn bcd32() {
+        assert_eq!(to_bcd32(freq!("118.005")), 0x0118_0050);
+        assert_eq!(to_bcd32(freq!("122.800")), 0x0122_8000);
+        assert_eq!(from_bcd32(0x0118_0050), Ok(freq!("118.005")));
+        assert_eq!(from_bcd32(0x0118_0051), Err(MsfsError::Frequency(RadioFrequencyError::NotWholeKhz)));
+        assert_eq!(from_bcd32(0x0118_0200), Err(MsfsError::Frequency(RadioFrequencyError::NotAChannel)));
+    }
+}
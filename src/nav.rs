@@ -0,0 +1,203 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::decimal::{self, DecimalError};
+
+/// The MHz range of the VHF NAV band, 108.00 to 117.95.
+pub const NAV_BAND_LEFT: std::ops::RangeInclusive<u16> = 108..=117;
+
+/// A VOR or ILS localizer frequency on the 50 kHz grid from 108.00 to 117.95.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "RawNavFrequency")]
+pub struct NavFrequency {
+    left: u16,
+    right: u16,
+}
+
+#[derive(Deserialize)]
+struct RawNavFrequency {
+    left: u16,
+    right: u16,
+}
+
+impl TryFrom<RawNavFrequency> for NavFrequency {
+    type Error = NavFrequencyError;
+    fn try_from(value: RawNavFrequency) -> Result<Self, Self::Error> {
+        NavFrequency::new(value.left, value.right)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NavChannelKind {
+    /// Odd tenths from 108.10 to 111.95.
+    Localizer,
+    Vor,
+}
+
+impl NavFrequency {
+    /// `right` is in kHz like [`crate::RadioFrequency::right`], so 110.30 is `new(110, 300)`.
+    pub const fn new(left: u16, right: u16) -> Result<NavFrequency, NavFrequencyError> {
+        if left < *NAV_BAND_LEFT.start() || left > *NAV_BAND_LEFT.end() {
+            return Err(NavFrequencyError::LeftOutOfBand);
+        }
+        if right > 999 {
+            return Err(NavFrequencyError::RightOutOfRange);
+        }
+        if !right.is_multiple_of(50) {
+            return Err(NavFrequencyError::NotAChannel);
+        }
+        Ok(NavFrequency { left, right })
+    }
+
+    /// Parses in const context, with the same rules as the `FromStr` impl. Takes 1 to 3 decimals.
+    pub const fn parse(s: &str) -> Result<NavFrequency, NavFrequencyError> {
+        match decimal::parse_decimal(s, 3, true) {
+            Ok((left, right)) if left <= u16::MAX as u32 => NavFrequency::new(left as u16, right),
+            Ok(_) => Err(NavFrequencyError::LeftOutOfBand),
+            Err(error) => Err(NavFrequencyError::from_decimal(error)),
+        }
+    }
+
+    pub const fn kind(&self) -> NavChannelKind {
+        let odd_tenth = (self.right / 100) % 2 == 1;
+        if self.left <= 111 && odd_tenth {
+            NavChannelKind::Localizer
+        } else {
+            NavChannelKind::Vor
+        }
+    }
+
+    pub const fn is_localizer(&self) -> bool {
+        matches!(self.kind(), NavChannelKind::Localizer)
+    }
+
+    pub const fn is_vor(&self) -> bool {
+        matches!(self.kind(), NavChannelKind::Vor)
+    }
+
+    pub const fn khz(&self) -> u32 {
+        self.left as u32 * 1000 + self.right as u32
+    }
+
+    pub const fn hz(&self) -> u32 {
+        self.khz() * 1000
+    }
+
+    pub const fn from_khz(khz: u32) -> Result<NavFrequency, NavFrequencyError> {
+        if khz / 1000 > u16::MAX as u32 {
+            return Err(NavFrequencyError::LeftOutOfBand);
+        }
+        NavFrequency::new((khz / 1000) as u16, (khz % 1000) as u16)
+    }
+
+    pub const fn frequency(&self) -> (u16, u16) {
+        (self.left, self.right)
+    }
+
+    pub const fn left(&self) -> u16 {
+        self.left
+    }
+
+    pub const fn right(&self) -> u16 {
+        self.right
+    }
+}
+
+impl std::fmt::Display for NavFrequency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:03}.{:02}", self.left, self.right / 10)
+    }
+}
+
+impl FromStr for NavFrequency {
+    type Err = NavFrequencyError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        NavFrequency::parse(s)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum NavFrequencyError {
+    LeftOutOfBand,
+    RightOutOfRange,
+    NotAChannel,
+    NotEnoughParts,
+    TooManyParts,
+    MissingMhz,
+    MissingDecimals,
+    TooManyDecimals,
+    UnexpectedSign,
+    UnexpectedWhitespace,
+    InvalidCharacter,
+}
+
+impl NavFrequencyError {
+    const fn from_decimal(error: DecimalError) -> Self {
+        match error {
+            DecimalError::NotEnoughParts => Self::NotEnoughParts,
+            DecimalError::TooManyParts => Self::TooManyParts,
+            DecimalError::MissingWhole => Self::MissingMhz,
+            DecimalError::MissingDecimals => Self::MissingDecimals,
+            DecimalError::TooManyDecimals => Self::TooManyDecimals,
+            DecimalError::UnexpectedSign => Self::UnexpectedSign,
+            DecimalError::UnexpectedWhitespace => Self::UnexpectedWhitespace,
+            DecimalError::InvalidCharacter => Self::InvalidCharacter,
+        }
+    }
+}
+
+impl std::fmt::Display for NavFrequencyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            Self::LeftOutOfBand => "MHz part outside the 108-117 band",
+            Self::RightOutOfRange => "kHz part outside 0-999",
+            Self::NotAChannel => "Not on the 50 kHz grid",
+            Self::NotEnoughParts => "Not enough parts",
+            Self::TooManyParts => "Too many parts",
+            Self::MissingMhz => "Missing MHz",
+            Self::MissingDecimals => "Missing decimals",
+            Self::TooManyDecimals => "More than three decimals",
+            Self::UnexpectedSign => "Unexpected sign",
+            Self::UnexpectedWhitespace => "Unexpected whitespace",
+            Self::InvalidCharacter => "Invalid character",
+        })
+    }
+}
+
+impl std::error::Error for NavFrequencyError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{NavChannelKind, NavFrequency, NavFrequencyError};
+
+    #[test]
+    fn validate() {
+        assert!(NavFrequency::new(108, 0).is_ok());
+        assert!(NavFrequency::new(117, 950).is_ok());
+        assert_eq!(NavFrequency::new(118, 0), Err(NavFrequencyError::LeftOutOfBand));
+        assert_eq!(NavFrequency::new(107, 950), Err(NavFrequencyError::LeftOutOfBand));
+        assert_eq!(NavFrequency::new(110, 1000), Err(NavFrequencyError::RightOutOfRange));
+        assert_eq!(NavFrequency::new(110, 325), Err(NavFrequencyError::NotAChannel));
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let ils: NavFrequency = "110.3".parse().unwrap();
+        assert_eq!(ils, NavFrequency::new(110, 300).unwrap());
+        assert_eq!(ils.to_string(), "110.30");
+        assert_eq!("113.85".parse::<NavFrequency>().unwrap().to_string(), "113.85");
+        assert_eq!("110.325".parse::<NavFrequency>(), Err(NavFrequencyError::NotAChannel));
+        assert_eq!("110".parse::<NavFrequency>(), Err(NavFrequencyError::NotEnoughParts));
+        assert_eq!("-110.30".parse::<NavFrequency>(), Err(NavFrequencyError::UnexpectedSign));
+    }
+
+    #[test]
+    fn kind() {
+        assert_eq!("108.10".parse::<NavFrequency>().unwrap().kind(), NavChannelKind::Localizer);
+        assert_eq!("111.95".parse::<NavFrequency>().unwrap().kind(), NavChannelKind::Localizer);
+        assert_eq!("108.00".parse::<NavFrequency>().unwrap().kind(), NavChannelKind::Vor);
+        assert_eq!("111.85".parse::<NavFrequency>().unwrap().kind(), NavChannelKind::Vor);
+        assert_eq!("113.10".parse::<NavFrequency>().unwrap().kind(), NavChannelKind::Vor);
+    }
+}
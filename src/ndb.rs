@@ -0,0 +1,192 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::decimal::{self, DecimalError};
+
+/// The kHz range an ADF receiver tunes.
+pub const ADF_BAND_KHZ: std::ops::RangeInclusive<u16> = 190..=1750;
+
+/// The kHz range NDBs are assigned in.
+pub const NDB_BAND_KHZ: std::ops::RangeInclusive<u16> = 190..=535;
+
+/// The kHz range of AM broadcast stations, used for ADF homing.
+pub const BROADCAST_BAND_KHZ: std::ops::RangeInclusive<u16> = 530..=1700;
+
+/// An ADF frequency between 190 and 1750 kHz with 0.5 kHz resolution, such as an NDB or an AM broadcast station.
+/// Serializes as a number of Hz.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "u32", into = "u32")]
+pub struct NdbFrequency {
+    half_khz: u16,
+}
+
+impl NdbFrequency {
+    /// `tenths` is the digit after the dot, so 415.5 is `new(415, 5)`. It must be 0 or 5.
+    pub const fn new(khz: u16, tenths: u16) -> Result<NdbFrequency, NdbFrequencyError> {
+        if khz < *ADF_BAND_KHZ.start() || khz > *ADF_BAND_KHZ.end() || (khz == *ADF_BAND_KHZ.end() && tenths != 0) {
+            return Err(NdbFrequencyError::OutOfBand);
+        }
+        if tenths != 0 && tenths != 5 {
+            return Err(NdbFrequencyError::NotAChannel);
+        }
+        Ok(NdbFrequency {
+            half_khz: khz * 2 + tenths / 5,
+        })
+    }
+
+    /// Parses in const context, with the same rules as the `FromStr` impl.
+    pub const fn parse(s: &str) -> Result<NdbFrequency, NdbFrequencyError> {
+        match decimal::parse_decimal(s, 1, false) {
+            Ok((khz, tenths)) if khz <= u16::MAX as u32 => NdbFrequency::new(khz as u16, tenths),
+            Ok(_) => Err(NdbFrequencyError::OutOfBand),
+            Err(error) => Err(NdbFrequencyError::from_decimal(error)),
+        }
+    }
+
+    /// Rejects any value that is not a multiple of 500 Hz.
+    pub const fn from_hz(hz: u32) -> Result<NdbFrequency, NdbFrequencyError> {
+        if !hz.is_multiple_of(500) {
+            return Err(NdbFrequencyError::NotAChannel);
+        }
+        if hz / 1000 > u16::MAX as u32 {
+            return Err(NdbFrequencyError::OutOfBand);
+        }
+        NdbFrequency::new((hz / 1000) as u16, (hz % 1000 / 100) as u16)
+    }
+
+    pub const fn hz(&self) -> u32 {
+        self.half_khz as u32 * 500
+    }
+
+    pub fn khz(&self) -> f64 {
+        self.half_khz as f64 / 2.0
+    }
+
+    /// The kHz part before the dot.
+    pub const fn whole_khz(&self) -> u16 {
+        self.half_khz / 2
+    }
+
+    pub const fn is_half_khz(&self) -> bool {
+        self.half_khz % 2 == 1
+    }
+
+    pub const fn is_ndb_band(&self) -> bool {
+        self.whole_khz() >= *NDB_BAND_KHZ.start() && self.hz() <= *NDB_BAND_KHZ.end() as u32 * 1000
+    }
+
+    pub const fn is_broadcast_band(&self) -> bool {
+        self.whole_khz() >= *BROADCAST_BAND_KHZ.start() && self.hz() <= *BROADCAST_BAND_KHZ.end() as u32 * 1000
+    }
+}
+
+impl TryFrom<u32> for NdbFrequency {
+    type Error = NdbFrequencyError;
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        NdbFrequency::from_hz(value)
+    }
+}
+
+impl From<NdbFrequency> for u32 {
+    fn from(value: NdbFrequency) -> Self {
+        value.hz()
+    }
+}
+
+impl std::fmt::Display for NdbFrequency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_half_khz() {
+            write!(f, "{}.5", self.whole_khz())
+        } else {
+            write!(f, "{}", self.whole_khz())
+        }
+    }
+}
+
+impl FromStr for NdbFrequency {
+    type Err = NdbFrequencyError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        NdbFrequency::parse(s)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum NdbFrequencyError {
+    OutOfBand,
+    NotAChannel,
+    TooManyParts,
+    MissingKhz,
+    MissingDecimals,
+    TooManyDecimals,
+    UnexpectedSign,
+    UnexpectedWhitespace,
+    InvalidCharacter,
+}
+
+impl NdbFrequencyError {
+    const fn from_decimal(error: DecimalError) -> Self {
+        match error {
+            // The dot is optional, so the parser never reports a missing one.
+            DecimalError::NotEnoughParts => Self::MissingDecimals,
+            DecimalError::TooManyParts => Self::TooManyParts,
+            DecimalError::MissingWhole => Self::MissingKhz,
+            DecimalError::MissingDecimals => Self::MissingDecimals,
+            DecimalError::TooManyDecimals => Self::TooManyDecimals,
+            DecimalError::UnexpectedSign => Self::UnexpectedSign,
+            DecimalError::UnexpectedWhitespace => Self::UnexpectedWhitespace,
+            DecimalError::InvalidCharacter => Self::InvalidCharacter,
+        }
+    }
+}
+
+impl std::fmt::Display for NdbFrequencyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            Self::OutOfBand => "Outside the 190-1750 kHz band",
+            Self::NotAChannel => "Not a multiple of 0.5 kHz",
+            Self::TooManyParts => "Too many parts",
+            Self::MissingKhz => "Missing kHz",
+            Self::MissingDecimals => "Missing decimals",
+            Self::TooManyDecimals => "More than one decimal",
+            Self::UnexpectedSign => "Unexpected sign",
+            Self::UnexpectedWhitespace => "Unexpected whitespace",
+            Self::InvalidCharacter => "Invalid character",
+        })
+    }
+}
+
+impl std::error::Error for NdbFrequencyError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{NdbFrequency, NdbFrequencyError};
+
+    #[test]
+    fn parse_and_display() {
+        let ndb: NdbFrequency = "375".parse().unwrap();
+        assert_eq!(ndb.hz(), 375_000);
+        assert_eq!(ndb.to_string(), "375");
+        let ndb: NdbFrequency = "415.5".parse().unwrap();
+        assert_eq!(ndb.hz(), 415_500);
+        assert_eq!(ndb.khz(), 415.5);
+        assert_eq!(ndb.to_string(), "415.5");
+        assert_eq!("1750".parse::<NdbFrequency>().unwrap().to_string(), "1750");
+
+        assert_eq!("415.3".parse::<NdbFrequency>(), Err(NdbFrequencyError::NotAChannel));
+        assert_eq!("415.55".parse::<NdbFrequency>(), Err(NdbFrequencyError::TooManyDecimals));
+        assert_eq!("189.5".parse::<NdbFrequency>(), Err(NdbFrequencyError::OutOfBand));
+        assert_eq!("1750.5".parse::<NdbFrequency>(), Err(NdbFrequencyError::OutOfBand));
+        assert_eq!("-375".parse::<NdbFrequency>(), Err(NdbFrequencyError::UnexpectedSign));
+        assert_eq!("".parse::<NdbFrequency>(), Err(NdbFrequencyError::MissingKhz));
+    }
+
+    #[test]
+    fn bands() {
+        let ndb = NdbFrequency::from_hz(375_000).unwrap();
+        assert!(ndb.is_ndb_band() && !ndb.is_broadcast_band());
+        let broadcast = NdbFrequency::from_hz(1_090_000).unwrap();
+        assert!(!broadcast.is_ndb_band() && broadcast.is_broadcast_band());
+        assert_eq!(NdbFrequency::from_hz(375_250), Err(NdbFrequencyError::NotAChannel));
+    }
+}
@@ -0,0 +1,113 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{FrequencyRange, RadioFrequency, RadioFrequencyError};
+
+/// The flight level above which 8.33 kHz spacing has been mandatory in the ICAO EUR region since 2007.
+pub const EUR_8_33_FLIGHT_LEVEL: u16 = 195;
+
+/// Regulatory rules on which channel spacing a frequency assignment may use.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpacingPolicy {
+    /// Any valid channel, as [`RadioFrequency::new`] checks.
+    Worldwide,
+    /// United States: 25 kHz channels only.
+    Faa,
+    /// ICAO EUR: 8.33 kHz channels are mandatory above `mandatory_above_fl`, or everywhere if `None`.
+    Eur { mandatory_above_fl: Option<u16> },
+    /// 8.33 kHz channels are only published inside these ranges.
+    SubBands(Vec<FrequencyRange>),
+}
+
+/// Why an assignment breaks a [`SpacingPolicy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SpacingViolation {
+    Khz8_33NotPermitted,
+    Khz8_33Mandatory,
+    Khz8_33OutsideSubBand,
+}
+
+impl SpacingPolicy {
+    /// Checks an assignment for a sector whose upper limit is `upper_fl`. `None` means unlimited.
+    pub fn check(&self, frequency: &RadioFrequency, upper_fl: Option<u16>) -> Result<(), SpacingViolation> {
+        match self {
+            Self::Worldwide => Ok(()),
+            Self::Faa if frequency.is_8_33_khz_spaced() => Err(SpacingViolation::Khz8_33NotPermitted),
+            Self::Faa => Ok(()),
+            Self::Eur { mandatory_above_fl } => {
+                let mandatory = match (mandatory_above_fl, upper_fl) {
+                    (Some(limit), Some(upper)) => upper > *limit,
+                    _ => true,
+                };
+                if mandatory && frequency.is_25_khz_spaced() {
+                    return Err(SpacingViolation::Khz8_33Mandatory);
+                }
+                Ok(())
+            }
+            Self::SubBands(ranges) => {
+                if frequency.is_8_33_khz_spaced() && !ranges.iter().any(|range| range.contains(frequency)) {
+                    return Err(SpacingViolation::Khz8_33OutsideSubBand);
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl RadioFrequency {
+    /// Like [`RadioFrequency::new`], but also checks `policy` for a sector with no upper limit.
+    pub fn new_with_policy(left: u16, right: u16, policy: &SpacingPolicy) -> Result<RadioFrequency, RadioFrequencyError> {
+        let frequency = RadioFrequency::new(left, right)?;
+        policy.check(&frequency, None)?;
+        Ok(frequency)
+    }
+}
+
+impl std::fmt::Display for SpacingViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            Self::Khz8_33NotPermitted => "8.33 kHz channels are not permitted",
+            Self::Khz8_33Mandatory => "8.33 kHz spacing is mandatory",
+            Self::Khz8_33OutsideSubBand => "8.33 kHz channel outside the permitted sub-bands",
+        })
+    }
+}
+
+impl std::error::Error for SpacingViolation {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{freq, FrequencyRange, RadioFrequency, RadioFrequencyError, SpacingPolicy, SpacingViolation, EUR_8_33_FLIGHT_LEVEL};
+
+    #[test]
+    fn policies() {
+        let khz_25 = freq!("132.600");
+        let khz_8_33 = freq!("132.605");
+        assert_eq!(SpacingPolicy::Worldwide.check(&khz_8_33, None), Ok(()));
+        assert_eq!(SpacingPolicy::Faa.check(&khz_25, None), Ok(()));
+        assert_eq!(SpacingPolicy::Faa.check(&khz_8_33, None), Err(SpacingViolation::Khz8_33NotPermitted));
+
+        let everywhere = SpacingPolicy::Eur { mandatory_above_fl: None };
+        assert_eq!(everywhere.check(&khz_25, Some(100)), Err(SpacingViolation::Khz8_33Mandatory));
+        assert_eq!(everywhere.check(&khz_8_33, Some(100)), Ok(()));
+
+        let upper = SpacingPolicy::Eur { mandatory_above_fl: Some(EUR_8_33_FLIGHT_LEVEL) };
+        assert_eq!(upper.check(&khz_25, Some(195)), Ok(()));
+        assert_eq!(upper.check(&khz_25, Some(245)), Err(SpacingViolation::Khz8_33Mandatory));
+        assert_eq!(upper.check(&khz_25, None), Err(SpacingViolation::Khz8_33Mandatory));
+
+        let sub_bands = SpacingPolicy::SubBands(vec![FrequencyRange::new(freq!("132.000"), freq!("133.990")).unwrap()]);
+        assert_eq!(sub_bands.check(&khz_8_33, None), Ok(()));
+        assert_eq!(sub_bands.check(&freq!("121.805"), None), Err(SpacingViolation::Khz8_33OutsideSubBand));
+        assert_eq!(sub_bands.check(&freq!("121.800"), None), Ok(()));
+    }
+
+    #[test]
+    fn new_with_policy() {
+        assert!(RadioFrequency::new_with_policy(132, 600, &SpacingPolicy::Faa).is_ok());
+        assert_eq!(
+            RadioFrequency::new_with_policy(132, 605, &SpacingPolicy::Faa),
+            Err(RadioFrequencyError::PolicyViolation(SpacingViolation::Khz8_33NotPermitted))
+        );
+        assert_eq!(RadioFrequency::new_with_policy(132, 620, &SpacingPolicy::Faa), Err(RadioFrequencyError::NotAChannel));
+    }
+}
@@ -0,0 +1,132 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{ChannelSpacing, RadioFrequency, BAND_LEFT, CHANNELS_PER_MHZ, CHANNEL_COUNT};
+
+/// Which channel names a radio steps through or an iterator yields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ChannelFilter {
+    /// Only the 25 kHz channels, as on a 760-channel radio.
+    Khz25,
+    /// Only the 8.33 kHz channel names.
+    Khz8_33,
+    /// Every channel in `VALID_CHANNELS`, as on an 8.33 kHz capable radio.
+    All,
+}
+
+impl ChannelFilter {
+    pub fn matches(&self, frequency: &RadioFrequency) -> bool {
+        match self {
+            Self::Khz25 => frequency.spacing() == ChannelSpacing::Khz25,
+            Self::Khz8_33 => frequency.spacing() == ChannelSpacing::Khz8_33,
+            Self::All => true,
+        }
+    }
+
+    /// How many channels in one MHz match this filter.
+    const fn per_mhz(&self) -> u16 {
+        match self {
+            // Every fourth entry in VALID_CHANNELS is a 25 kHz channel.
+            Self::Khz25 => CHANNELS_PER_MHZ / 4,
+            Self::Khz8_33 => CHANNELS_PER_MHZ - CHANNELS_PER_MHZ / 4,
+            Self::All => CHANNELS_PER_MHZ,
+        }
+    }
+}
+
+impl RadioFrequency {
+    /// The next channel up that matches `filter`, wrapping from the top of the band to 118.000.
+    pub fn next_channel(&self, filter: ChannelFilter) -> RadioFrequency {
+        self.step_within(filter, 0, CHANNEL_COUNT, 1)
+    }
+
+    /// The next channel down that matches `filter`, wrapping from 118.000 to the top of the band.
+    pub fn previous_channel(&self, filter: ChannelFilter) -> RadioFrequency {
+        self.step_within(filter, 0, CHANNEL_COUNT, -1)
+    }
+
+    /// Turns the outer (MHz) knob by `steps` clicks, keeping the kHz part and wrapping between 118 and 136.
+    pub fn step_outer(&self, steps: i32) -> RadioFrequency {
+        let band_width = (BAND_LEFT.end() - BAND_LEFT.start() + 1) as i32;
+        let steps = steps.rem_euclid(band_width);
+        let mhz = ((self.left() - BAND_LEFT.start()) as i32 + steps).rem_euclid(band_width) as u16;
+        RadioFrequency::from_ordinal(mhz * CHANNELS_PER_MHZ + self.ordinal() % CHANNELS_PER_MHZ)
+    }
+
+    /// Turns the inner (kHz) knob by `steps` clicks through the channels matching `filter`.
+    /// Like a real COM radio, this wraps within the current MHz and never changes the MHz part.
+    pub fn step_inner(&self, steps: i32, filter: ChannelFilter) -> RadioFrequency {
+        let start = self.ordinal() - self.ordinal() % CHANNELS_PER_MHZ;
+        // A full turn through the MHz comes back to the same channel, so at most one turn is walked.
+        // From a channel the filter skips, the first click only lands on the filter.
+        let turn = filter.per_mhz() as u32;
+        let clicks = match steps.unsigned_abs() {
+            0 => 0,
+            clicks if filter.matches(self) => clicks % turn,
+            clicks => (clicks - 1) % turn + 1,
+        };
+        (0..clicks).fold(*self, |frequency, _| {
+            frequency.step_within(filter, start, CHANNELS_PER_MHZ, steps.signum())
+        })
+    }
+
+    /// Moves one channel matching `filter` in `direction`, wrapping inside the `len` channels from `start`.
+    fn step_within(&self, filter: ChannelFilter, start: u16, len: u16, direction: i32) -> RadioFrequency {
+        let mut position = self.ordinal() - start;
+        loop {
+            position = (position as i32 + direction).rem_euclid(len as i32) as u16;
+            let candidate = RadioFrequency::from_ordinal(start + position);
+            if filter.matches(&candidate) {
+                return candidate;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{freq, ChannelFilter};
+
+    #[test]
+    fn next_and_previous() {
+        assert_eq!(freq!("118.000").next_channel(ChannelFilter::All), freq!("118.005"));
+        assert_eq!(freq!("118.015").next_channel(ChannelFilter::All), freq!("118.025"));
+        assert_eq!(freq!("118.000").next_channel(ChannelFilter::Khz25), freq!("118.025"));
+        assert_eq!(freq!("118.005").next_channel(ChannelFilter::Khz25), freq!("118.025"));
+        assert_eq!(freq!("118.015").next_channel(ChannelFilter::Khz8_33), freq!("118.030"));
+        assert_eq!(freq!("121.990").next_channel(ChannelFilter::All), freq!("122.000"));
+        assert_eq!(freq!("136.990").next_channel(ChannelFilter::All), freq!("118.000"));
+        assert_eq!(freq!("136.975").next_channel(ChannelFilter::Khz25), freq!("118.000"));
+        assert_eq!(freq!("118.000").previous_channel(ChannelFilter::All), freq!("136.990"));
+        assert_eq!(freq!("118.000").previous_channel(ChannelFilter::Khz25), freq!("136.975"));
+        assert_eq!(freq!("122.000").previous_channel(ChannelFilter::All), freq!("121.990"));
+    }
+
+    #[test]
+    fn knobs() {
+        assert_eq!(freq!("121.500").step_outer(1), freq!("122.500"));
+        assert_eq!(freq!("136.990").step_outer(1), freq!("118.990"));
+        assert_eq!(freq!("118.005").step_outer(-1), freq!("136.005"));
+        assert_eq!(freq!("118.005").step_outer(-20), freq!("136.005"));
+
+        assert_eq!(freq!("121.975").step_inner(1, ChannelFilter::Khz25), freq!("121.000"));
+        assert_eq!(freq!("121.000").step_inner(-1, ChannelFilter::Khz25), freq!("121.975"));
+        assert_eq!(freq!("121.990").step_inner(1, ChannelFilter::All), freq!("121.000"));
+        assert_eq!(freq!("121.500").step_inner(3, ChannelFilter::All), freq!("121.515"));
+        assert_eq!(freq!("121.500").step_inner(0, ChannelFilter::All), freq!("121.500"));
+    }
+
+    #[test]
+    fn large_knob_steps() {
+        assert_eq!(freq!("136.000").step_outer(i32::MAX), freq!("136.000").step_outer(i32::MAX % 19));
+        assert_eq!(freq!("118.000").step_outer(i32::MIN), freq!("118.000").step_outer(i32::MIN % 19));
+        assert_eq!(freq!("121.500").step_outer(19), freq!("121.500"));
+
+        assert_eq!(freq!("121.500").step_inner(40, ChannelFilter::Khz25), freq!("121.500"));
+        assert_eq!(freq!("121.505").step_inner(120, ChannelFilter::Khz8_33), freq!("121.505"));
+        assert_eq!(freq!("121.500").step_inner(121, ChannelFilter::Khz8_33), freq!("121.505"));
+        assert_eq!(freq!("121.500").step_inner(1, ChannelFilter::Khz8_33), freq!("121.505"));
+        assert_eq!(freq!("121.500").step_inner(161, ChannelFilter::All), freq!("121.505"));
+        assert_eq!(freq!("121.500").step_inner(i32::MAX, ChannelFilter::All), freq!("121.500").step_inner(i32::MAX % 160, ChannelFilter::All));
+        assert_eq!(freq!("121.500").step_inner(i32::MIN, ChannelFilter::Khz25), freq!("121.500").step_inner(i32::MIN % 40, ChannelFilter::Khz25));
+    }
+}
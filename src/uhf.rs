@@ -0,0 +1,190 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    carrier_offset_hz, channel_position, decimal, split_carrier_hz, well_known, ChannelSpacing, RadioFrequency,
+    RadioFrequencyError,
+};
+
+/// The MHz range of the military UHF air band. The band ends at 399.975 (25 kHz) / 399.990 (8.33 kHz).
+pub const UHF_BAND_LEFT: std::ops::RangeInclusive<u16> = 225..=399;
+
+/// A military UHF air band channel, with the same channel names and spacing rules as [`RadioFrequency`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "RawUhfFrequency")]
+pub struct UhfFrequency {
+    left: u16,
+    right: u16,
+}
+
+#[derive(Deserialize)]
+struct RawUhfFrequency {
+    left: u16,
+    right: u16,
+}
+
+impl TryFrom<RawUhfFrequency> for UhfFrequency {
+    type Error = RadioFrequencyError;
+    fn try_from(value: RawUhfFrequency) -> Result<Self, Self::Error> {
+        UhfFrequency::new(value.left, value.right)
+    }
+}
+
+impl UhfFrequency {
+    /// Military emergency frequency, the UHF counterpart of 121.500.
+    pub const MILITARY_GUARD: UhfFrequency = UhfFrequency { left: 243, right: 0 };
+
+    pub const fn new(left: u16, right: u16) -> Result<UhfFrequency, RadioFrequencyError> {
+        if left < *UHF_BAND_LEFT.start() || left > *UHF_BAND_LEFT.end() {
+            return Err(RadioFrequencyError::LeftOutOfBand);
+        }
+        if let Err(error) = channel_position(right) {
+            return Err(error);
+        }
+        Ok(UhfFrequency { left, right })
+    }
+
+    /// Parses in const context, with the same rules as the `FromStr` impl.
+    pub const fn parse(s: &str) -> Result<UhfFrequency, RadioFrequencyError> {
+        match decimal::parse_decimal(s, 3, true) {
+            Ok((left, right)) if left <= u16::MAX as u32 => UhfFrequency::new(left as u16, right),
+            Ok(_) => Err(RadioFrequencyError::LeftOutOfBand),
+            Err(error) => Err(RadioFrequencyError::from_decimal(error)),
+        }
+    }
+
+    pub const fn is_8_33_khz_spaced(&self) -> bool {
+        !self.is_25_khz_spaced()
+    }
+
+    pub const fn is_25_khz_spaced(&self) -> bool {
+        self.right.is_multiple_of(25)
+    }
+
+    pub const fn spacing(&self) -> ChannelSpacing {
+        if self.is_25_khz_spaced() {
+            ChannelSpacing::Khz25
+        } else {
+            ChannelSpacing::Khz8_33
+        }
+    }
+
+    /// The actual carrier frequency in Hz, as opposed to the channel name. See [`RadioFrequency::carrier_hz`].
+    pub const fn carrier_hz(&self) -> u32 {
+        self.left as u32 * 1_000_000 + carrier_offset_hz(self.right)
+    }
+
+    /// See [`RadioFrequency::from_carrier_hz`].
+    pub fn from_carrier_hz(hz: u32, spacing: ChannelSpacing) -> Result<UhfFrequency, RadioFrequencyError> {
+        let (left, right) = split_carrier_hz(hz, spacing)?;
+        UhfFrequency::new(left, right)
+    }
+
+    /// The channel name in kHz.
+    pub const fn khz(&self) -> u32 {
+        self.left as u32 * 1000 + self.right as u32
+    }
+
+    /// The channel name in Hz.
+    pub const fn hz(&self) -> u32 {
+        self.khz() * 1000
+    }
+
+    pub const fn frequency(&self) -> (u16, u16) {
+        (self.left, self.right)
+    }
+
+    pub const fn left(&self) -> u16 {
+        self.left
+    }
+
+    pub const fn right(&self) -> u16 {
+        self.right
+    }
+}
+
+impl std::fmt::Display for UhfFrequency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:03}.{:03}", self.left, self.right)
+    }
+}
+
+impl FromStr for UhfFrequency {
+    type Err = RadioFrequencyError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        UhfFrequency::parse(s)
+    }
+}
+
+/// The VHF and UHF frequencies a station publishes for the same position, as in a station record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PairedFrequency {
+    pub vhf: RadioFrequency,
+    pub uhf: UhfFrequency,
+}
+
+impl PairedFrequency {
+    /// 121.500 paired with 243.000.
+    pub const GUARD: PairedFrequency = PairedFrequency {
+        vhf: well_known::EMERGENCY,
+        uhf: UhfFrequency::MILITARY_GUARD,
+    };
+
+    pub const fn new(vhf: RadioFrequency, uhf: UhfFrequency) -> PairedFrequency {
+        PairedFrequency { vhf, uhf }
+    }
+}
+
+impl From<(RadioFrequency, UhfFrequency)> for PairedFrequency {
+    fn from(value: (RadioFrequency, UhfFrequency)) -> Self {
+        PairedFrequency::new(value.0, value.1)
+    }
+}
+
+impl From<PairedFrequency> for (RadioFrequency, UhfFrequency) {
+    fn from(value: PairedFrequency) -> Self {
+        (value.vhf, value.uhf)
+    }
+}
+
+impl std::fmt::Display for PairedFrequency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.vhf, self.uhf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{freq, ChannelSpacing, PairedFrequency, RadioFrequencyError, UhfFrequency};
+
+    #[test]
+    fn validate() {
+        assert_eq!("243.0".parse::<UhfFrequency>().unwrap(), UhfFrequency::MILITARY_GUARD);
+        assert!(UhfFrequency::new(225, 0).is_ok());
+        assert!(UhfFrequency::new(399, 975).is_ok());
+        assert_eq!(UhfFrequency::new(400, 0), Err(RadioFrequencyError::LeftOutOfBand));
+        assert_eq!(UhfFrequency::new(224, 975), Err(RadioFrequencyError::LeftOutOfBand));
+        assert_eq!(UhfFrequency::new(300, 1000), Err(RadioFrequencyError::RightOutOfRange));
+        assert_eq!(UhfFrequency::new(300, 20), Err(RadioFrequencyError::NotAChannel));
+        assert_eq!("251.15".parse::<UhfFrequency>().unwrap().to_string(), "251.150");
+    }
+
+    #[test]
+    fn carrier() {
+        let frequency: UhfFrequency = "300.010".parse().unwrap();
+        assert_eq!(frequency.spacing(), ChannelSpacing::Khz8_33);
+        assert_eq!(frequency.carrier_hz(), 300_008_333);
+        assert_eq!(UhfFrequency::from_carrier_hz(300_008_333, ChannelSpacing::Khz8_33).unwrap(), frequency);
+        assert_eq!(UhfFrequency::from_carrier_hz(300_008_332, ChannelSpacing::Khz8_33).unwrap(), frequency);
+        assert_eq!(UhfFrequency::from_carrier_hz(300_000_000, ChannelSpacing::Khz25).unwrap().to_string(), "300.000");
+    }
+
+    #[test]
+    fn pairing() {
+        assert_eq!(PairedFrequency::GUARD.to_string(), "121.500/243.000");
+        let paired = PairedFrequency::from((freq!("132.605"), "277.825".parse().unwrap()));
+        assert_eq!(paired.uhf.frequency(), (277, 825));
+        assert_eq!(<(_, _)>::from(paired).0, freq!("132.605"));
+    }
+}
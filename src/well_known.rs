@@ -0,0 +1,103 @@
+//! Frequencies with a special meaning, and [`RadioFrequency::roles`] to recognise them.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{freq, FrequencyRange, RadioFrequency};
+
+/// International aeronautical emergency frequency, also known as guard.
+pub const EMERGENCY: RadioFrequency = freq!("121.500");
+
+/// Air-to-air communication, used over oceanic and remote areas.
+pub const AIR_TO_AIR: RadioFrequency = freq!("123.450");
+
+/// Search and rescue on-scene coordination.
+pub const SEARCH_AND_RESCUE: RadioFrequency = freq!("123.100");
+
+/// FAA UNICOM, MULTICOM, air-to-air and other advisory frequencies in the United States.
+pub const FAA_ADVISORY: [RadioFrequency; 11] = [
+    freq!("121.950"),
+    freq!("122.700"),
+    freq!("122.725"),
+    freq!("122.750"),
+    freq!("122.800"),
+    freq!("122.900"),
+    freq!("122.975"),
+    freq!("123.000"),
+    freq!("123.025"),
+    freq!("123.050"),
+    freq!("123.075"),
+];
+
+/// The range used for surface movement control.
+pub const GROUND: FrequencyRange = match FrequencyRange::new(freq!("121.600"), freq!("121.975")) {
+    Some(range) => range,
+    None => panic!("invalid ground range"),
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FrequencyRole {
+    Emergency,
+    AirToAir,
+    SearchAndRescue,
+    FaaAdvisory,
+    Ground,
+}
+
+impl FrequencyRole {
+    /// Whether a frequency with this role should not be assigned to an ATC sector.
+    pub fn is_reserved(&self) -> bool {
+        !matches!(self, Self::Ground)
+    }
+}
+
+impl RadioFrequency {
+    /// Every special role this frequency has. Empty for an ordinary frequency.
+    pub fn roles(&self) -> Vec<FrequencyRole> {
+        let mut roles = Vec::new();
+        if *self == EMERGENCY {
+            roles.push(FrequencyRole::Emergency);
+        }
+        if *self == AIR_TO_AIR {
+            roles.push(FrequencyRole::AirToAir);
+        }
+        if *self == SEARCH_AND_RESCUE {
+            roles.push(FrequencyRole::SearchAndRescue);
+        }
+        if FAA_ADVISORY.contains(self) {
+            roles.push(FrequencyRole::FaaAdvisory);
+        }
+        if GROUND.contains(self) {
+            roles.push(FrequencyRole::Ground);
+        }
+        roles
+    }
+
+    pub fn is_emergency(&self) -> bool {
+        *self == EMERGENCY
+    }
+
+    /// Whether any role of this frequency makes it unsuitable for an ATC sector.
+    pub fn is_reserved(&self) -> bool {
+        self.roles().iter().any(FrequencyRole::is_reserved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{freq, FrequencyRole};
+
+    #[test]
+    fn roles() {
+        assert_eq!(freq!("121.500").roles(), [FrequencyRole::Emergency]);
+        assert!(freq!("121.500").is_emergency());
+        assert_eq!(freq!("123.450").roles(), [FrequencyRole::AirToAir]);
+        assert_eq!(freq!("123.100").roles(), [FrequencyRole::SearchAndRescue]);
+        assert_eq!(freq!("122.750").roles(), [FrequencyRole::FaaAdvisory]);
+        assert_eq!(freq!("121.950").roles(), [FrequencyRole::FaaAdvisory, FrequencyRole::Ground]);
+        assert_eq!(freq!("121.600").roles(), [FrequencyRole::Ground]);
+        assert_eq!(freq!("121.980").roles(), []);
+        assert!(freq!("123.450").is_reserved());
+        assert!(!freq!("121.700").is_reserved());
+        assert!(!freq!("132.605").is_reserved());
+    }
+}
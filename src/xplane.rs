@@ -0,0 +1,125 @@
+//! X-Plane COM and NAV integer datarefs.
+//!
+//! `sim/cockpit/radios/com1_freq_hz`, `sim/cockpit2/radios/actuators/com1_frequency_hz` and the NAV
+//! equivalents are in 10 kHz units, so 12150 is 121.50. Like MSFS BCD16 this drops the third decimal, so COM decoding takes a [`ChannelFilter`].
+//! `com1_frequency_hz_833` is in kHz, so 121505 is the 121.505 channel name. Plugins that write the
+//! 8.33 kHz carrier rounded or truncated to the kHz instead, such as 118008 for 118.010, are accepted as well.
+
+use crate::{channels, ChannelFilter, NavFrequency, NavFrequencyError, RadioFrequency, RadioFrequencyError};
+
+pub fn to_com_frequency_hz(frequency: RadioFrequency) -> i32 {
+    (frequency.khz() / 10) as i32
+}
+
+/// Decodes for a 25 kHz radio, which is always unique.
+pub fn from_com_frequency_hz(value: i32) -> Result<RadioFrequency, XPlaneError> {
+    from_com_frequency_hz_with(value, ChannelFilter::Khz25)
+}
+
+/// Keeps only channels matching `filter`. With anything but [`ChannelFilter::Khz25`], a value such as 12150
+/// (121.500 or 121.505) is reported as ambiguous.
+pub fn from_com_frequency_hz_with(value: i32, filter: ChannelFilter) -> Result<RadioFrequency, XPlaneError> {
+    let value = u32::try_from(value).map_err(|_| XPlaneError::Negative)?;
+    let candidates = channels::truncated_to_10_khz(value, filter);
+    match candidates[..] {
+        [] => Err(XPlaneError::NoChannel),
+        [frequency] => Ok(frequency),
+        _ => Err(XPlaneError::Ambiguous(candidates)),
+    }
+}
+
+/// The channel name in kHz.
+pub fn to_com_frequency_hz_833(frequency: RadioFrequency) -> i32 {
+    frequency.khz() as i32
+}
+
+/// Takes a channel name in kHz, or an 8.33 kHz carrier rounded or truncated to the kHz.
+pub fn from_com_frequency_hz_833(value: i32) -> Result<RadioFrequency, XPlaneError> {
+    let khz = u32::try_from(value).map_err(|_| XPlaneError::Negative)?;
+    Ok(RadioFrequency::from_khz_or_carrier_khz(khz)?)
+}
+
+pub fn to_nav_frequency_hz(frequency: NavFrequency) -> i32 {
+    (frequency.khz() / 10) as i32
+}
+
+pub fn from_nav_frequency_hz(value: i32) -> Result<NavFrequency, XPlaneError> {
+    let value = u32::try_from(value).map_err(|_| XPlaneError::Negative)?;
+    Ok(NavFrequency::from_khz(value.saturating_mul(10))?)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum XPlaneError {
+    Negative,
+    NoChannel,
+    Ambiguous(Vec<RadioFrequency>),
+    Frequency(RadioFrequencyError),
+    Nav(NavFrequencyError),
+}
+
+impl From<RadioFrequencyError> for XPlaneError {
+    fn from(value: RadioFrequencyError) -> Self {
+        Self::Frequency(value)
+    }
+}
+
+impl From<NavFrequencyError> for XPlaneError {
+    fn from(value: NavFrequencyError) -> Self {
+        Self::Nav(value)
+    }
+}
+
+impl std::fmt::Display for XPlaneError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Negative => write!(f, "Negative dataref value"),
+            Self::NoChannel => write!(f, "No channel matches"),
+            Self::Ambiguous(candidates) => write!(f, "Ambiguous, {} channels match", candidates.len()),
+            Self::Frequency(error) => write!(f, "{error}"),
+            Self::Nav(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for XPlaneError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::freq;
+
+    #[test]
+    fn com() {
+        assert_eq!(to_com_frequency_hz(freq!("121.500")), 12150);
+        assert_eq!(to_com_frequency_hz(freq!("121.525")), 12152);
+        assert_eq!(from_com_frequency_hz(12150), Ok(freq!("121.500")));
+        assert_eq!(from_com_frequency_hz(12152), Ok(freq!("121.525")));
+        assert_eq!(from_com_frequency_hz(12151), Err(XPlaneError::NoChannel));
+        assert_eq!(from_com_frequency_hz(-1), Err(XPlaneError::Negative));
+        assert_eq!(from_com_frequency_hz_with(12151, ChannelFilter::All), Err(XPlaneError::Ambiguous(vec![freq!("121.510"), freq!("121.515")])));
+    }
+
+    #[test]
+    fn com_833() {
+        assert_eq!(to_com_frequency_hz_833(freq!("121.505")), 121505);
+        assert_eq!(from_com_frequency_hz_833(121505), Ok(freq!("121.505")));
+        assert_eq!(from_com_frequency_hz_833(118008), Ok(freq!("118.010")));
+        assert_eq!(from_com_frequency_hz_833(118017), Ok(freq!("118.015")));
+        assert_eq!(from_com_frequency_hz_833(118016), Ok(freq!("118.015")));
+        assert_eq!(from_com_frequency_hz_833(118000), Ok(freq!("118.000")));
+        assert_eq!(from_com_frequency_hz_833(118020), Err(XPlaneError::Frequency(RadioFrequencyError::NotAChannel)));
+        for frequency in crate::RadioFrequency::channels(ChannelFilter::All) {
+            let carrier_khz = (frequency.carrier_hz() + 500) / 1000;
+            if frequency.is_8_33_khz_spaced() && carrier_khz % 25 != 0 {
+                assert_eq!(from_com_frequency_hz_833(carrier_khz as i32), Ok(frequency));
+            }
+        }
+    }
+
+    #[test]
+    fn nav() {
+        assert_eq!(to_nav_frequency_hz("110.30".parse().unwrap()), 11030);
+        assert_eq!(from_nav_frequency_hz(11035), Ok("110.35".parse().unwrap()));
+        assert_eq!(from_nav_frequency_hz(11032), Err(XPlaneError::Nav(NavFrequencyError::NotAChannel)));
+    }
+}